@@ -0,0 +1,201 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt::Write;
+use std::fs;
+use tictactoe::board::{bit_board::BitBoard, square_name, Board};
+
+use crate::engine::{Engine, Score};
+
+pub struct Options {
+    pub root: Board,
+    /// Merge positions that are rotations or reflections of each other
+    pub symmetric: bool,
+    /// Stop expanding this many plies below the root
+    pub depth: Option<u32>,
+    pub output: String,
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            root: Board::EMPTY,
+            symmetric: false,
+            depth: None,
+            output: String::from("game.dot"),
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--symmetric" => options.symmetric = true,
+                "--depth" => {
+                    let depth = args.next().ok_or("--depth needs a number")?;
+                    options.depth = Some(depth.parse().map_err(|_| "--depth needs a number")?);
+                }
+                "--root" => {
+                    let notation = args.next().ok_or("--root needs a position")?;
+                    options.root = notation
+                        .parse()
+                        .map_err(|error| format!("Invalid root {notation:?}: {error}"))?;
+                }
+                "--output" => options.output = args.next().ok_or("--output needs a file")?,
+                _ => return Err(format!("Unknown option {arg:?}")),
+            }
+        }
+
+        if !options.root.is_legal() {
+            return Err(String::from(
+                "Root position can't be reached from an empty board",
+            ));
+        }
+        Ok(options)
+    }
+}
+
+const fn x_to_move(board: Board) -> bool {
+    board.x.count() == board.o.count()
+}
+
+fn is_terminal(board: Board) -> bool {
+    board.x.has_won() || board.o.has_won() || board.occupied() == BitBoard::FULL
+}
+
+/// Score of a position from X's point of view
+fn score_for_x(engine: &mut Engine, board: Board) -> Score {
+    let score = engine.search(board, x_to_move(board));
+    if x_to_move(board) {
+        score
+    } else {
+        Score(-score.0)
+    }
+}
+
+/// Write the position DAG below `options.root` as a Graphviz file
+pub fn run(options: &Options) {
+    let root = if options.symmetric {
+        options.root.canonical()
+    } else {
+        options.root
+    };
+
+    // Searched lazily, since symmetric children may not be reachable from this orientation of the root
+    let mut engine = Engine::new();
+
+    // Positions in the order they were first reached
+    let mut seen = HashSet::from([root.index()]);
+    let mut order = Vec::new();
+    // Moves from a parent to a child, and whether they keep the parent's best score
+    let mut edges: BTreeMap<(usize, usize), (Vec<String>, bool)> = BTreeMap::new();
+
+    let mut queue = VecDeque::from([(root, 0)]);
+    while let Some((board, depth)) = queue.pop_front() {
+        order.push(board);
+        if is_terminal(board) || options.depth.is_some_and(|cap| depth >= cap) {
+            continue;
+        }
+
+        let best_score = engine.search(board, x_to_move(board));
+        let mut not_occupied = !board.occupied() & BitBoard::FULL;
+        while not_occupied != BitBoard::EMPTY {
+            #[allow(clippy::cast_possible_truncation)]
+            let index = not_occupied.pop() as u8;
+            let mut child = board;
+            if x_to_move(board) {
+                child.x.set(index);
+            } else {
+                child.o.set(index);
+            }
+            if options.symmetric {
+                child = child.canonical();
+            }
+
+            let optimal = -engine.search(child, x_to_move(child)).0 == best_score.0;
+            let edge = edges
+                .entry((board.index(), child.index()))
+                .or_insert_with(|| (Vec::new(), optimal));
+            edge.0.push(square_name(index));
+
+            if seen.insert(child.index()) {
+                queue.push_back((child, depth + 1));
+            }
+        }
+    }
+
+    let mut dot = String::new();
+    writeln!(dot, "digraph tictactoe {{").unwrap();
+    writeln!(
+        dot,
+        "\tnode [shape=box, style=filled, fontname=\"monospace\"];"
+    )
+    .unwrap();
+    for board in &order {
+        let colour = match score_for_x(&mut engine, *board) {
+            Score::WINNING => "lightcoral",
+            Score::LOSING => "lightblue",
+            Score::DRAWING => "lightgrey",
+            _ => unreachable!(),
+        };
+        let label = board.notation().replace('/', "\\n");
+        let peripheries = if is_terminal(*board) { 2 } else { 1 };
+        writeln!(
+            dot,
+            "\tp{:#x} [label=\"{label}\", fillcolor={colour}, peripheries={peripheries}];",
+            board.index()
+        )
+        .unwrap();
+    }
+    for ((parent, child), (moves, optimal)) in &edges {
+        let style = if *optimal {
+            ", penwidth=2, color=darkgreen"
+        } else {
+            ""
+        };
+        writeln!(
+            dot,
+            "\tp{parent:#x} -> p{child:#x} [label=\"{}\"{style}];",
+            moves.join(", ")
+        )
+        .unwrap();
+    }
+    writeln!(dot, "}}").unwrap();
+
+    fs::write(&options.output, dot).unwrap();
+    println!(
+        "Wrote {} positions and {} edges to {}",
+        order.len(),
+        edges.len(),
+        options.output
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = parse(&["--symmetric", "--depth", "2", "--root", "X--/-O-/---"]).unwrap();
+        assert!(options.symmetric);
+        assert_eq!(options.depth, Some(2));
+        assert_eq!(options.root.notation(), "X--/-O-/---");
+        assert_eq!(options.output, "game.dot");
+        assert!(parse(&["--depth", "deep"]).is_err());
+        assert!(parse(&["--wide"]).is_err());
+    }
+
+    #[test]
+    fn rejects_unreachable_roots() {
+        for root in [
+            "XX-/---/---",
+            "OO-/X--/---",
+            // X won, but O moved after it
+            "XXX/OO-/O--",
+            // O won, but X moved after it
+            "OOO/XX-/XX-",
+        ] {
+            assert!(parse(&["--root", root]).is_err(), "{root}");
+        }
+        assert!(parse(&["--root", "XXX/OO-/---"]).is_ok());
+    }
+}
@@ -4,7 +4,7 @@ pub struct Engine {
     pub transposition_table: Vec<Score>,
 }
 
-const LOOKUP_SIZE: usize = 1 << 18;
+pub const LOOKUP_SIZE: usize = 1 << 18;
 
 #[derive(Clone, Copy, PartialEq, Debug, Eq)]
 pub struct Score(pub i8);
@@ -45,7 +45,7 @@ impl Engine {
                 new_board.x |= place;
             } else {
                 new_board.o |= place;
-            };
+            }
             let score = -self.search(new_board, !x_to_move).0;
             if score > best_score.0 {
                 best_score = Score(score);
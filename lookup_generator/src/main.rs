@@ -11,10 +11,61 @@ use tictactoe::board::{bit_board::BitBoard, Board};
 
 use crate::engine::Score;
 
+mod dot;
 mod engine;
+mod perft;
+mod puzzle;
+mod retrograde;
+mod stats;
+mod strategy;
+mod verify;
 
-/// Generate a massive lookup table of positions to score
 fn main() {
+    match std::env::args().nth(1).as_deref() {
+        None | Some("generate") => generate(),
+        Some("stats") => stats::run(),
+        Some("retrograde") => match retrograde::Options::parse(std::env::args().skip(2)) {
+            Ok(options) => retrograde::run(&options),
+            Err(error) => usage(&error),
+        },
+        Some("verify") => {
+            if !verify::run() {
+                std::process::exit(1);
+            }
+        }
+        Some("dot") => match dot::Options::parse(std::env::args().skip(2)) {
+            Ok(options) => dot::run(&options),
+            Err(error) => usage(&error),
+        },
+        Some("perft") => match perft::Options::parse(std::env::args().skip(2)) {
+            Ok(options) => perft::run(&options),
+            Err(error) => usage(&error),
+        },
+        Some("puzzle") => match puzzle::Options::parse(std::env::args().skip(2)) {
+            Ok(options) => puzzle::run(&options),
+            Err(error) => usage(&error),
+        },
+        Some("strategy") => match strategy::Options::parse(std::env::args().skip(2)) {
+            Ok(options) => strategy::run(&options),
+            Err(error) => usage(&error),
+        },
+        Some(mode) => usage(&format!("Unknown mode {mode:?}")),
+    }
+}
+
+fn usage(error: &str) -> ! {
+    eprintln!("{error}");
+    eprintln!("Usage: lookup_generator [generate|stats|verify]");
+    eprintln!("       lookup_generator retrograde [--output FILE]");
+    eprintln!("       lookup_generator perft [DEPTH] [--divide] [--root X-O/---/---]");
+    eprintln!("       lookup_generator strategy x|o [--table]");
+    eprintln!("       lookup_generator puzzle [--difficulty N] [--seed N]");
+    eprintln!("       lookup_generator dot [--symmetric] [--depth N] [--root X-O/---/---] [--output FILE]");
+    std::process::exit(2);
+}
+
+/// Generate a massive lookup table of positions to score
+fn generate() {
     let mut engine = Engine::new();
     engine.search(Board::EMPTY, true);
 
@@ -24,7 +75,7 @@ fn main() {
     for (position, score) in engine.transposition_table.iter().enumerate() {
         if *score == Score::UNKNOWN {
             continue;
-        };
+        }
 
         #[allow(clippy::cast_possible_truncation)]
         let (x, o) = {
@@ -58,7 +109,7 @@ fn main() {
     for (position, score) in engine.transposition_table.iter().enumerate() {
         if *score == Score::UNKNOWN {
             continue;
-        };
+        }
 
         #[allow(clippy::cast_possible_truncation)]
         let (x, o) = {
@@ -79,18 +130,18 @@ fn main() {
     }
     matcher.push('\t');
     matcher.push_str(&drawing[..drawing.len() - 2]);
-    matcher.push_str("=> 0,\n");
+    matcher.push_str("=> Some(0),\n");
 
     matcher.push('\t');
     matcher.push_str(&winning[..winning.len() - 2]);
-    matcher.push_str("=> 1,\n");
+    matcher.push_str("=> Some(1),\n");
 
     matcher.push('\t');
     matcher.push_str(&losing[..losing.len() - 2]);
-    matcher.push_str("=> -1,\n");
+    matcher.push_str("=> Some(-1),\n");
 
     matcher.push('\t');
-    matcher.push_str("_ => unreachable!(),\n");
+    matcher.push_str("_ => None,\n");
 
     matcher.push_str("};");
     fs::write("match.rs", matcher).unwrap();
@@ -0,0 +1,97 @@
+use tictactoe::board::{square_name, Board};
+use tictactoe::position::Position;
+use tictactoe::{perft, perft_divide};
+
+/// `perft` from the empty board for depths 0 to 9
+pub const PERFT: [u64; 10] = [1, 9, 72, 504, 3024, 15120, 54720, 148_176, 200_448, 127_872];
+
+pub struct Options {
+    pub root: Position,
+    pub depth: u32,
+    /// Break the count down by first move
+    pub divide: bool,
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            root: Position::START,
+            depth: 9,
+            divide: false,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--divide" => options.divide = true,
+                "--root" => {
+                    let notation = args.next().ok_or("--root needs a position")?;
+                    let board: Board = notation
+                        .parse()
+                        .map_err(|error| format!("Invalid root {notation:?}: {error}"))?;
+                    options.root = Position::new(board);
+                }
+                depth => {
+                    options.depth = depth
+                        .parse()
+                        .map_err(|_| format!("Unknown option {arg:?}"))?;
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Print move path counts, checking them against the known values when starting from the empty board
+pub fn run(options: &Options) {
+    if options.divide {
+        for (index, count) in perft_divide(options.root, options.depth) {
+            println!("{}: {count}", square_name(index));
+        }
+    }
+
+    for depth in 0..=options.depth {
+        let count = perft(options.root, depth);
+        println!("perft({depth}) = {count}");
+        // Every game is over by depth 9, so deeper counts are 0 and there's nothing to check
+        if let Some(expected) = PERFT.get(depth as usize) {
+            if options.root == Position::START {
+                assert_eq!(count, *expected, "perft({depth})");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_published_values() {
+        for (depth, expected) in (0..).zip(PERFT) {
+            assert_eq!(perft(Position::START, depth), expected, "perft({depth})");
+        }
+        assert_eq!(perft(Position::START, 10), 0);
+    }
+
+    #[test]
+    fn divide_adds_up() {
+        for depth in 1..=9 {
+            let divide = perft_divide(Position::START, depth);
+            assert_eq!(divide.len(), 9);
+            let total: u64 = divide.iter().map(|(_, count)| count).sum();
+            assert_eq!(total, perft(Position::START, depth));
+        }
+        assert!(perft_divide(Position::START, 0).is_empty());
+    }
+
+    #[test]
+    fn parses_options() {
+        let args = |args: &[&str]| args.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let options =
+            Options::parse(args(&["5", "--divide", "--root", "X--/---/---"]).into_iter()).unwrap();
+        assert_eq!(options.depth, 5);
+        assert!(options.divide);
+        assert_eq!(options.root.board.notation(), "X--/---/---");
+        assert!(Options::parse(args(&["--root"]).into_iter()).is_err());
+        assert!(Options::parse(args(&["deep"]).into_iter()).is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, stdin, stdout, BufRead, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tictactoe::board::bit_board::BitBoard;
+use tictactoe::board::{parse_square, square_name, Board};
+use tictactoe::position::Position;
+use tictactoe::random::Random;
+
+use crate::engine::{Engine, Score};
+use crate::retrograde::{Entry, Table};
+
+pub struct Options {
+    pub seed: u64,
+    /// Only give puzzles of this difficulty instead of adapting to the streak
+    pub difficulty: Option<u8>,
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut seed = None;
+        let mut difficulty = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let value = args.next().ok_or("--seed needs a number")?;
+                    seed = Some(value.parse().map_err(|_| "--seed needs a number")?);
+                }
+                "--difficulty" => {
+                    let value = args.next().ok_or("--difficulty needs a number")?;
+                    difficulty = Some(value.parse().map_err(|_| "--difficulty needs a number")?);
+                }
+                _ => return Err(format!("Unknown option {arg:?}")),
+            }
+        }
+        Ok(Self {
+            #[allow(clippy::cast_possible_truncation)]
+            seed: seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |time| time.as_nanos() as u64)
+            }),
+            difficulty,
+        })
+    }
+}
+
+const fn letter(x: bool) -> char {
+    if x {
+        'X'
+    } else {
+        'O'
+    }
+}
+
+/// A position where exactly one of several moves keeps the best result
+struct Puzzle {
+    position: Position,
+    /// `Score::WINNING` to find the win, `Score::DRAWING` to save the draw
+    goal: Score,
+    solution: u8,
+    /// Plies left in the game with perfect play
+    difficulty: u8,
+}
+
+/// Every puzzle reachable from the empty board, one per class of symmetric positions,
+/// by difficulty
+fn collect(engine: &mut Engine, table: &Table) -> BTreeMap<u8, Vec<Puzzle>> {
+    engine.search(Board::EMPTY, true);
+    let reachable: Vec<Board> = engine
+        .transposition_table
+        .iter()
+        .enumerate()
+        .filter(|(_, score)| **score != Score::UNKNOWN)
+        .map(|(index, _)| Board::from_index(index))
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut puzzles: BTreeMap<u8, Vec<Puzzle>> = BTreeMap::new();
+    for board in reachable {
+        let position = Position::new(board);
+        let moves = position.moves();
+        if moves.count() < 2 || !seen.insert(board.canonical().index()) {
+            continue;
+        }
+        let goal = engine.search(board, position.x_to_move);
+        if goal == Score::LOSING {
+            continue;
+        }
+
+        let mut keeping = BitBoard::EMPTY;
+        let mut remaining = moves;
+        while remaining != BitBoard::EMPTY {
+            #[allow(clippy::cast_possible_truncation)]
+            let index = remaining.pop() as u8;
+            let child = position.play(index).board;
+            if -engine.search(child, !position.x_to_move).0 == goal.0 {
+                keeping.set(index);
+            }
+        }
+        if keeping.count() == 1 {
+            let entry = table.get(board).unwrap();
+            puzzles.entry(entry.distance).or_default().push(Puzzle {
+                position,
+                goal,
+                #[allow(clippy::cast_possible_truncation)]
+                solution: keeping.first() as u8,
+                difficulty: entry.distance,
+            });
+        }
+    }
+    puzzles
+}
+
+/// The fastest winning reply for the side to move in a position it wins
+fn winning_reply(table: &Table, position: Position) -> u8 {
+    let mut best: Option<(u8, u8)> = None;
+    let mut moves = position.moves();
+    while moves != BitBoard::EMPTY {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = moves.pop() as u8;
+        let entry = table.get_position(position.play(index)).unwrap();
+        if entry.score == Score::LOSING && best.is_none_or(|(_, fastest)| entry.distance < fastest)
+        {
+            best = Some((index, entry.distance));
+        }
+    }
+    best.unwrap().0
+}
+
+/// Why `index` was right or wrong
+fn explain(table: &Table, puzzle: &Puzzle, index: u8) -> String {
+    let side = letter(puzzle.position.x_to_move);
+    let name = square_name(index);
+    let child = puzzle.position.play(index);
+    // Entries are for the side to move after the answer
+    let Entry { score, distance } = table.get_position(child).unwrap();
+
+    if index == puzzle.solution {
+        return if puzzle.goal == Score::WINNING {
+            if distance == 0 {
+                format!("Right! {name} completes a line")
+            } else {
+                format!("Right! After {name}, {side} wins within {distance} more plies whatever happens")
+            }
+        } else {
+            format!("Right! {name} is the only move that holds the draw")
+        };
+    }
+
+    let answer = square_name(puzzle.solution);
+    if score == Score::WINNING {
+        let opponent = letter(!puzzle.position.x_to_move);
+        let reply = square_name(winning_reply(table, child));
+        let how = if distance == 1 {
+            format!("{opponent} wins with {reply}")
+        } else {
+            format!("{opponent} answers {reply} and wins within {distance} plies")
+        };
+        format!("Wrong: after {name}, {how}. The move was {answer}")
+    } else {
+        format!("Wrong: {name} only draws. {answer} wins")
+    }
+}
+
+/// Asks puzzles read from `input` until it closes, moving up a difficulty after every two right
+/// answers in a row. Stops with the error once `output` can't be written to
+fn ask(
+    table: &Table,
+    puzzles: &BTreeMap<u8, Vec<Puzzle>>,
+    difficulties: &[u8],
+    random: &mut Random,
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    let (mut streak, mut best_streak, mut solved, mut asked) = (0_u32, 0, 0, 0);
+    let mut step = 0;
+    let mut line = String::new();
+    'puzzles: loop {
+        let choices = &puzzles[&difficulties[step]];
+        #[allow(clippy::cast_possible_truncation)]
+        let puzzle = &choices[random.below(choices.len() as u32) as usize];
+
+        writeln!(output)?;
+        writeln!(output, "{}", puzzle.position.board)?;
+        let task = if puzzle.goal == Score::WINNING {
+            "win"
+        } else {
+            "save the draw"
+        };
+        writeln!(
+            output,
+            "{} to play and {task} (difficulty {})",
+            letter(puzzle.position.x_to_move),
+            puzzle.difficulty
+        )?;
+
+        let index = loop {
+            write!(output, "> ")?;
+            output.flush()?;
+            line.clear();
+            if input.read_line(&mut line).unwrap_or(0) == 0 {
+                break 'puzzles;
+            }
+            match parse_square(line.trim()) {
+                Some(index) if puzzle.position.moves().get(index) => break index,
+                Some(_) => writeln!(output, "That square is taken")?,
+                None => writeln!(output, "Type a square such as b2")?,
+            }
+        };
+
+        asked += 1;
+        writeln!(output, "{}", explain(table, puzzle, index))?;
+        if index == puzzle.solution {
+            solved += 1;
+            streak += 1;
+            best_streak = best_streak.max(streak);
+            if streak % 2 == 0 {
+                step = (step + 1).min(difficulties.len() - 1);
+            }
+        } else {
+            streak = 0;
+            step = step.saturating_sub(1);
+        }
+        writeln!(output, "Streak: {streak} (best {best_streak})")?;
+    }
+
+    writeln!(output)?;
+    writeln!(
+        output,
+        "Solved {solved} of {asked}, best streak {best_streak}"
+    )
+}
+
+/// Ask puzzles until stdin closes, or stdout does, such as when piped into `head`
+pub fn run(options: &Options) {
+    let mut engine = Engine::new();
+    let table = Table::solve();
+    let puzzles = collect(&mut engine, &table);
+    let counts: Vec<String> = puzzles
+        .iter()
+        .map(|(difficulty, puzzles)| format!("{} at difficulty {difficulty}", puzzles.len()))
+        .collect();
+    let mut stdout = stdout().lock();
+    if writeln!(stdout, "Puzzles: {}", counts.join(", ")).is_err() {
+        return;
+    }
+
+    let difficulties: Vec<u8> = match options.difficulty {
+        Some(difficulty) if puzzles.contains_key(&difficulty) => vec![difficulty],
+        Some(difficulty) => {
+            eprintln!("There are no puzzles at difficulty {difficulty}");
+            std::process::exit(2);
+        }
+        None => puzzles.keys().copied().collect(),
+    };
+
+    let mut random = Random::new(options.seed);
+    // Nothing is left to do once the output is gone
+    let _ = ask(
+        &table,
+        &puzzles,
+        &difficulties,
+        &mut random,
+        stdin().lock(),
+        stdout,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        args.iter()
+            .map(|arg| (*arg).to_owned())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    fn parses_options() {
+        let options = Options::parse(args(&["--seed", "5", "--difficulty", "3"])).unwrap();
+        assert_eq!((options.seed, options.difficulty), (5, Some(3)));
+        assert_eq!(Options::parse(args(&[])).unwrap().difficulty, None);
+        assert!(Options::parse(args(&["--seed"])).is_err());
+        assert!(Options::parse(args(&["--difficulty", "hard"])).is_err());
+        assert!(Options::parse(args(&["--level", "1"])).is_err());
+    }
+
+    #[test]
+    fn every_puzzle_has_one_answer() {
+        let mut engine = Engine::new();
+        let table = Table::solve();
+        let puzzles = collect(&mut engine, &table);
+        assert!(!puzzles.is_empty());
+        for (difficulty, puzzles) in &puzzles {
+            for puzzle in puzzles {
+                assert_eq!(puzzle.difficulty, *difficulty);
+                assert!(puzzle.position.moves().get(puzzle.solution));
+                let Position { board, x_to_move } = puzzle.position;
+                assert_eq!(engine.search(board, x_to_move), puzzle.goal);
+                let mut moves = puzzle.position.moves();
+                while moves != BitBoard::EMPTY {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let index = moves.pop() as u8;
+                    let child = puzzle.position.play(index);
+                    let kept = -engine.search(child.board, child.x_to_move).0 == puzzle.goal.0;
+                    assert_eq!(kept, index == puzzle.solution, "{}", board.notation());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn explains_answers() {
+        let table = Table::solve();
+        // O threatens the a3–c1 diagonal, so X has to take c1 to hold the draw
+        let position = Position::new("OX-/-O-/X--".parse().unwrap());
+        let puzzle = Puzzle {
+            position,
+            goal: Score::DRAWING,
+            solution: 2,
+            difficulty: 0,
+        };
+        assert_eq!(
+            explain(&table, &puzzle, 2),
+            "Right! c1 is the only move that holds the draw"
+        );
+        assert_eq!(
+            explain(&table, &puzzle, 8),
+            "Wrong: after c3, O wins with c1. The move was c1"
+        );
+    }
+
+    #[test]
+    fn stops_when_the_output_closes() {
+        struct Closed;
+        impl Write for Closed {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::BrokenPipe.into())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let table = Table::solve();
+        let puzzles = collect(&mut Engine::new(), &table);
+        let difficulties: Vec<u8> = puzzles.keys().copied().collect();
+        let ask = |input: &str, output: &mut dyn Write| {
+            ask(
+                &table,
+                &puzzles,
+                &difficulties,
+                &mut Random::new(1),
+                input.as_bytes(),
+                output,
+            )
+        };
+        let mut output = Vec::new();
+        assert!(ask("d4\n", &mut output).is_ok());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("> Type a square such as b2\n> \n"));
+        assert!(output.ends_with("Solved 0 of 0, best streak 0\n"));
+        assert!(
+            ask("b2\n", &mut Closed).is_err_and(|error| error.kind() == io::ErrorKind::BrokenPipe)
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use std::fmt::Write;
+use std::fs;
+use tictactoe::board::{bit_board::BitBoard, Board};
+use tictactoe::position::Position;
+
+use crate::engine::{Score, LOOKUP_SIZE};
+
+/// Solved result of a position for the side to move
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Entry {
+    pub score: Score,
+    /// Plies until the game ends when both sides play perfectly:
+    /// the winner takes the fastest win and the loser the slowest loss
+    pub distance: u8,
+}
+
+/// What is known about a position while its moves are still being solved
+#[derive(Clone, Copy, Default)]
+struct Pending {
+    /// Moves not solved yet
+    remaining: u8,
+    fastest_win: Option<u8>,
+    draw: Option<u8>,
+    slowest_loss: Option<u8>,
+}
+
+impl Pending {
+    fn add(&mut self, child: Entry) {
+        let distance = child.distance + 1;
+        match child.score {
+            // The opponent losing after this move means winning here
+            Score::LOSING => {
+                self.fastest_win = Some(self.fastest_win.map_or(distance, |d| d.min(distance)));
+            }
+            Score::DRAWING => self.draw = Some(distance),
+            Score::WINNING => {
+                self.slowest_loss = Some(self.slowest_loss.map_or(distance, |d| d.max(distance)));
+            }
+            _ => unreachable!(),
+        }
+        self.remaining -= 1;
+    }
+
+    fn finish(self) -> Entry {
+        debug_assert_eq!(self.remaining, 0);
+        let (score, distance) = match (self.fastest_win, self.draw) {
+            (Some(distance), _) => (Score::WINNING, distance),
+            (None, Some(distance)) => (Score::DRAWING, distance),
+            (None, None) => (Score::LOSING, self.slowest_loss.unwrap()),
+        };
+        Entry { score, distance }
+    }
+}
+
+/// Every legal position with its outcome and distance to the end, indexed by `Board::index`
+pub struct Table {
+    pub entries: Vec<Option<Entry>>,
+}
+
+impl Table {
+    /// Seed the finished positions, then work backwards one ply at a time,
+    /// undoing the last move of each solved position to reach its parents
+    pub fn solve() -> Self {
+        let mut by_ply: [Vec<Board>; 10] = Default::default();
+        for index in 0..LOOKUP_SIZE {
+            let board = Board::from_index(index);
+            if board.is_legal() {
+                by_ply[board.occupied().count() as usize].push(board);
+            }
+        }
+
+        let mut entries = vec![None; LOOKUP_SIZE];
+        let mut pending = vec![Pending::default(); LOOKUP_SIZE];
+        for board in by_ply.iter().flatten() {
+            if board.x.has_won() || board.o.has_won() {
+                entries[board.index()] = Some(Entry {
+                    score: Score::LOSING,
+                    distance: 0,
+                });
+            } else if board.occupied() == BitBoard::FULL {
+                entries[board.index()] = Some(Entry {
+                    score: Score::DRAWING,
+                    distance: 0,
+                });
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let remaining = (9 - board.occupied().count()) as u8;
+                pending[board.index()].remaining = remaining;
+            }
+        }
+
+        for ply in (1..10).rev() {
+            for board in &by_ply[ply] {
+                let entry =
+                    entries[board.index()].expect("every move of a position is solved first");
+
+                // Whoever moved last
+                let x_moved = board.x.count() != board.o.count();
+                let mut last_moves = if x_moved { board.x } else { board.o };
+                while last_moves != BitBoard::EMPTY {
+                    let taken = !BitBoard::new(1 << last_moves.pop());
+                    let mut parent = *board;
+                    if x_moved {
+                        parent.x &= taken;
+                    } else {
+                        parent.o &= taken;
+                    }
+                    // Finished positions have no moves to undo into them
+                    if parent.x.has_won() || parent.o.has_won() {
+                        continue;
+                    }
+                    pending[parent.index()].add(entry);
+                }
+            }
+
+            for board in &by_ply[ply - 1] {
+                if entries[board.index()].is_none() {
+                    entries[board.index()] = Some(pending[board.index()].finish());
+                }
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// The entry for a board from a game X started
+    pub fn get(&self, board: Board) -> Option<Entry> {
+        self.entries[board.index()]
+    }
+
+    /// The entry for a position from a game either side started.
+    /// A game O started is the same as one X started with the marks swapped
+    pub fn get_position(&self, position: Position) -> Option<Entry> {
+        let Position { board, x_to_move } = position;
+        let (x, o) = (board.x.count(), board.o.count());
+        let x_moved_first = if x_to_move { x == o } else { x == o + 1 };
+        if x_moved_first {
+            self.get(board)
+        } else {
+            self.get(swap(board))
+        }
+    }
+}
+
+const fn swap(board: Board) -> Board {
+    Board {
+        x: board.o,
+        o: board.x,
+    }
+}
+
+pub struct Options {
+    pub output: String,
+}
+
+impl Options {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self {
+            output: String::from("dtm.txt"),
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--output" => options.output = args.next().ok_or("--output needs a file")?,
+                _ => return Err(format!("Unknown option {arg:?}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Solve every legal position backwards and write each with its distance to the end, for games
+/// X started and then for games O started
+pub fn run(options: &Options) {
+    let table = Table::solve();
+
+    // Positions per distance for wins, draws and losses, from the side to move's point of view
+    let mut histogram = [[0_u32; 3]; 10];
+    let mut output = String::new();
+    let mut o_first = String::new();
+    for (index, entry) in table.entries.iter().enumerate() {
+        let Some(entry) = entry else {
+            continue;
+        };
+        let (column, letter) = match entry.score {
+            Score::WINNING => (0, 'W'),
+            Score::DRAWING => (1, 'D'),
+            Score::LOSING => (2, 'L'),
+            _ => unreachable!(),
+        };
+        histogram[entry.distance as usize][column] += 1;
+        let board = Board::from_index(index);
+        writeln!(output, "{} x {letter} {}", board.notation(), entry.distance).unwrap();
+        writeln!(
+            o_first,
+            "{} o {letter} {}",
+            swap(board).notation(),
+            entry.distance
+        )
+        .unwrap();
+    }
+    output.push_str(&o_first);
+    if let Err(error) = fs::write(&options.output, output) {
+        eprintln!("Couldn't write {}: {error}", options.output);
+        std::process::exit(1);
+    }
+
+    println!("distance      win     draw     loss");
+    for (distance, [win, draw, loss]) in histogram.iter().enumerate() {
+        println!("{distance:>8} {win:>8} {draw:>8} {loss:>8}");
+    }
+    let total: u32 = histogram.iter().flatten().sum();
+    println!(
+        "Solved {total} legal positions for each side moving first, written to {}",
+        options.output
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::Engine;
+
+    #[test]
+    fn agrees_with_search_for_either_side_first() {
+        let table = Table::solve();
+        let (mut x_first, mut o_first) = (Engine::new(), Engine::new());
+        for index in 0..LOOKUP_SIZE {
+            let board = Board::from_index(index);
+            if !board.is_legal() {
+                continue;
+            }
+            let x_to_move = board.x.count() == board.o.count();
+            let entry = table.get(board).unwrap();
+            assert_eq!(entry.score, x_first.search(board, x_to_move));
+            let position = Position { board, x_to_move };
+            assert_eq!(table.get_position(position), Some(entry));
+
+            // The same game with O moving first
+            let swapped = swap(board);
+            let position = Position {
+                board: swapped,
+                x_to_move: !x_to_move,
+            };
+            assert_eq!(table.get_position(position), Some(entry));
+            assert_eq!(entry.score, o_first.search(swapped, !x_to_move));
+        }
+    }
+
+    #[test]
+    fn measures_distances() {
+        let table = Table::solve();
+        let distance = |notation: &str| table.get(notation.parse().unwrap()).unwrap();
+        assert_eq!(
+            distance("---/---/---"),
+            Entry {
+                score: Score::DRAWING,
+                distance: 9
+            }
+        );
+        assert_eq!(
+            distance("XX-/OO-/---"),
+            Entry {
+                score: Score::WINNING,
+                distance: 1
+            }
+        );
+        assert_eq!(
+            distance("XXX/OO-/---"),
+            Entry {
+                score: Score::LOSING,
+                distance: 0
+            }
+        );
+    }
+
+    #[test]
+    fn parses_options() {
+        let parse = |args: &[&str]| Options::parse(args.iter().map(ToString::to_string));
+        assert_eq!(parse(&[]).unwrap().output, "dtm.txt");
+        assert_eq!(parse(&["--output", "a.txt"]).unwrap().output, "a.txt");
+        assert!(parse(&["--output"]).is_err());
+        assert!(parse(&["--wide"]).is_err());
+    }
+}
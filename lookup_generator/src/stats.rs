@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+use std::io::{stdout, Write};
+use tictactoe::board::{bit_board::BitBoard, Board};
+use tictactoe::{perft, position::Position};
+
+use crate::engine::{Engine, Score};
+use crate::perft::PERFT;
+
+/// Published totals for standard tic-tac-toe, checked by the tests and after every run
+const POSITIONS_PER_PLY: [u64; 10] = [1, 9, 72, 252, 756, 1260, 1520, 1140, 390, 78];
+const CANONICAL_PER_PLY: [u64; 10] = [1, 3, 12, 38, 108, 174, 204, 153, 57, 15];
+const GAMES_PER_LENGTH: [u64; 10] = [0, 0, 0, 0, 0, 1440, 5328, 47952, 72576, 127_872];
+const GAMES: u64 = 255_168;
+/// X wins, O wins, draws
+const TERMINAL_POSITIONS: [u64; 3] = [626, 316, 16];
+const GAME_RESULTS: [u64; 3] = [131_184, 77904, 46080];
+
+#[derive(Default)]
+pub struct Statistics {
+    pub positions_per_ply: [u64; 10],
+    pub canonical_per_ply: [u64; 10],
+    pub games_per_length: [u64; 10],
+    /// X wins, O wins, draws
+    pub terminal_positions: [u64; 3],
+    /// X wins, O wins, draws
+    pub game_results: [u64; 3],
+    /// Legal moves summed over every non-terminal position
+    pub moves: u64,
+}
+
+impl Statistics {
+    /// Walks every position `Engine::search` reaches from the empty board, ply by ply
+    pub fn collect() -> Self {
+        let mut engine = Engine::new();
+        engine.search(Board::EMPTY, true);
+
+        let mut by_ply: [Vec<Board>; 10] = Default::default();
+        for (index, score) in engine.transposition_table.iter().enumerate() {
+            if *score == Score::UNKNOWN {
+                continue;
+            }
+            let board = Board::from_index(index);
+            by_ply[board.occupied().count() as usize].push(board);
+        }
+
+        let mut statistics = Self::default();
+        // Number of move sequences that lead to each position
+        let mut paths = vec![0_u64; engine.transposition_table.len()];
+        paths[Board::EMPTY.index()] = 1;
+
+        for (ply, boards) in by_ply.iter().enumerate() {
+            statistics.positions_per_ply[ply] = boards.len() as u64;
+            statistics.canonical_per_ply[ply] = boards
+                .iter()
+                .map(|board| board.canonical().index())
+                .collect::<HashSet<_>>()
+                .len() as u64;
+
+            for board in boards {
+                let x_to_move = board.x.count() == board.o.count();
+                let result = if board.x.has_won() {
+                    Some(0)
+                } else if board.o.has_won() {
+                    Some(1)
+                } else if board.occupied() == BitBoard::FULL {
+                    Some(2)
+                } else {
+                    None
+                };
+
+                let path_count = paths[board.index()];
+                if let Some(result) = result {
+                    statistics.terminal_positions[result] += 1;
+                    statistics.game_results[result] += path_count;
+                    statistics.games_per_length[ply] += path_count;
+                    continue;
+                }
+
+                let mut not_occupied = !board.occupied() & BitBoard::FULL;
+                while not_occupied != BitBoard::EMPTY {
+                    let place = BitBoard::new(1 << not_occupied.pop());
+                    let mut new_board = *board;
+                    if x_to_move {
+                        new_board.x |= place;
+                    } else {
+                        new_board.o |= place;
+                    }
+                    paths[new_board.index()] += path_count;
+                    statistics.moves += 1;
+                }
+            }
+        }
+
+        statistics
+    }
+
+    pub fn games(&self) -> u64 {
+        self.games_per_length.iter().sum()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_branching_factor(&self) -> f64 {
+        let positions: u64 = self.positions_per_ply.iter().sum();
+        let terminal: u64 = self.terminal_positions.iter().sum();
+        self.moves as f64 / (positions - terminal) as f64
+    }
+
+    /// Panics if any count disagrees with the published totals
+    pub fn check(&self) {
+        assert_eq!(
+            self.positions_per_ply, POSITIONS_PER_PLY,
+            "positions per ply"
+        );
+        assert_eq!(
+            self.canonical_per_ply, CANONICAL_PER_PLY,
+            "canonical classes per ply"
+        );
+        assert_eq!(self.games_per_length, GAMES_PER_LENGTH, "games by length");
+        assert_eq!(self.games(), GAMES, "complete games");
+        assert_eq!(
+            self.terminal_positions, TERMINAL_POSITIONS,
+            "terminal positions"
+        );
+        assert_eq!(self.game_results, GAME_RESULTS, "game results");
+        for (depth, expected) in PERFT.iter().enumerate() {
+            #[allow(clippy::cast_possible_truncation)]
+            let count = perft(Position::START, depth as u32);
+            assert_eq!(count, *expected, "perft({depth})");
+        }
+    }
+}
+
+/// Print the game-tree statistics, then check them against the published totals
+pub fn run() {
+    let statistics = Statistics::collect();
+    let mut stdout = stdout().lock();
+
+    writeln!(stdout, "ply  positions  canonical  games ending").unwrap();
+    for ply in 0..10 {
+        writeln!(
+            stdout,
+            "{ply:>3}  {:>9}  {:>9}  {:>12}",
+            statistics.positions_per_ply[ply],
+            statistics.canonical_per_ply[ply],
+            statistics.games_per_length[ply]
+        )
+        .unwrap();
+    }
+    writeln!(
+        stdout,
+        "all  {:>9}  {:>9}  {:>12}",
+        statistics.positions_per_ply.iter().sum::<u64>(),
+        statistics.canonical_per_ply.iter().sum::<u64>(),
+        statistics.games()
+    )
+    .unwrap();
+    writeln!(stdout).unwrap();
+
+    let [x, o, draw] = statistics.terminal_positions;
+    writeln!(
+        stdout,
+        "Terminal positions: {x} X wins, {o} O wins, {draw} draws"
+    )
+    .unwrap();
+    let [x, o, draw] = statistics.game_results;
+    writeln!(
+        stdout,
+        "Complete games: {x} X wins, {o} O wins, {draw} draws"
+    )
+    .unwrap();
+    writeln!(
+        stdout,
+        "Average branching factor: {:.3}",
+        statistics.average_branching_factor()
+    )
+    .unwrap();
+
+    statistics.check();
+    writeln!(stdout, "All totals match the published values").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_published_totals() {
+        let statistics = Statistics::collect();
+        statistics.check();
+    }
+}
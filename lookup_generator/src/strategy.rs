@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use tictactoe::board::square_name;
+use tictactoe::position::Position;
+
+use crate::engine::{Engine, Score};
+
+pub struct Options {
+    /// Side the strategy is for
+    pub x: bool,
+    /// Print one line per position instead of a tree
+    pub table: bool,
+}
+
+impl Options {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut side = None;
+        let mut table = false;
+        for arg in args {
+            match arg.as_str() {
+                "x" | "X" => side = Some(true),
+                "o" | "O" => side = Some(false),
+                "--table" => table = true,
+                _ => return Err(format!("Unknown option {arg:?}")),
+            }
+        }
+        Ok(Self {
+            x: side.ok_or("strategy needs a side, x or o")?,
+            table,
+        })
+    }
+}
+
+const fn letter(x: bool) -> char {
+    if x {
+        'X'
+    } else {
+        'O'
+    }
+}
+
+/// Chooses one perfect move for `side` wherever it is to move, answering every reply from the other side
+struct Strategy {
+    engine: Engine,
+    side: bool,
+    /// Decisions in the smallest strategy tree below each canonical position, which symmetric
+    /// positions share
+    sizes: HashMap<usize, u32>,
+}
+
+impl Strategy {
+    fn score(&mut self, position: Position) -> i8 {
+        self.engine.search(position.board, position.x_to_move).0
+    }
+
+    /// Replies from `position`, one per class of symmetric results, with every square that leads to it
+    fn replies(position: Position) -> Vec<(Position, Vec<u8>)> {
+        let mut replies: Vec<(Position, Vec<u8>)> = Vec::new();
+        let mut moves = position.moves();
+        while moves.count() != 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            let index = moves.pop() as u8;
+            let child = position.play(index);
+            let canonical = child.board.canonical();
+            match replies
+                .iter_mut()
+                .find(|(other, _)| other.board.canonical() == canonical)
+            {
+                Some((_, squares)) => squares.push(index),
+                None => replies.push((child, vec![index])),
+            }
+        }
+        replies
+    }
+
+    /// The optimal move that keeps the strategy smallest, with the size of what follows it
+    fn choose(&mut self, position: Position) -> (u8, u32) {
+        let best = self.score(position);
+        let mut choice = None;
+        for (child, squares) in Self::replies(position) {
+            if -self.score(child) != best {
+                continue;
+            }
+            let size = self.size(child);
+            if choice.is_none_or(|(_, smallest)| size < smallest) {
+                choice = Some((squares[0], size));
+            }
+        }
+        choice.unwrap()
+    }
+
+    /// Decisions in the smallest strategy tree from `position`, one for each move of `side` and
+    /// for each reply to answer, as [`Strategy::print_tree`] prints them
+    fn size(&mut self, position: Position) -> u32 {
+        if position.is_over() {
+            return 0;
+        }
+        let key = position.board.canonical().index();
+        if let Some(size) = self.sizes.get(&key) {
+            return *size;
+        }
+
+        let size = if position.x_to_move == self.side {
+            1 + self.choose(position).1
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            Self::replies(position)
+                .into_iter()
+                .map(|(child, squares)| self.size(child) * squares.len() as u32)
+                .sum()
+        };
+        self.sizes.insert(key, size);
+        size
+    }
+
+    fn print_tree(&mut self, position: Position, depth: usize, output: &mut String) {
+        if position.is_over() {
+            return;
+        }
+        let indent = "  ".repeat(depth);
+        if position.x_to_move == self.side {
+            let (index, _) = self.choose(position);
+            writeln!(
+                output,
+                "{indent}{} {}",
+                letter(self.side),
+                square_name(index)
+            )
+            .unwrap();
+            self.print_tree(position.play(index), depth + 1, output);
+            return;
+        }
+
+        // Each reply gets its own line: symmetric replies need answers mapped to their squares
+        let mut moves = position.moves();
+        while moves.count() != 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            let reply = moves.pop() as u8;
+            let child = position.play(reply);
+            if child.is_over() {
+                writeln!(
+                    output,
+                    "{indent}{} {} ends the game",
+                    letter(!self.side),
+                    square_name(reply)
+                )
+                .unwrap();
+                continue;
+            }
+            let (index, _) = self.choose(child);
+            writeln!(
+                output,
+                "{indent}{} {}: {} {}",
+                letter(!self.side),
+                square_name(reply),
+                letter(self.side),
+                square_name(index)
+            )
+            .unwrap();
+            self.print_tree(child.play(index), depth + 1, output);
+        }
+    }
+
+    /// Each distinct decision in the strategy, as `notation: move`
+    fn print_table(&mut self, position: Position, seen: &mut HashSet<usize>, output: &mut String) {
+        if position.is_over() {
+            return;
+        }
+        if position.x_to_move == self.side {
+            if !seen.insert(position.board.canonical().index()) {
+                return;
+            }
+            let (index, _) = self.choose(position);
+            writeln!(
+                output,
+                "{}: {}",
+                position.board.notation(),
+                square_name(index)
+            )
+            .unwrap();
+            self.print_table(position.play(index), seen, output);
+        } else {
+            for (child, _) in Self::replies(position) {
+                self.print_table(child, seen, output);
+            }
+        }
+    }
+}
+
+/// Print the smallest perfect-play strategy for one side from the empty board
+pub fn run(options: &Options) {
+    let mut strategy = Strategy {
+        engine: Engine::new(),
+        side: options.x,
+        sizes: HashMap::new(),
+    };
+
+    // The table also counts the distinct positions, so it's built either way
+    let mut table = String::new();
+    let mut seen = HashSet::new();
+    strategy.print_table(Position::START, &mut seen, &mut table);
+    if options.table {
+        print!("{table}");
+    } else {
+        let mut tree = String::new();
+        strategy.print_tree(Position::START, 0, &mut tree);
+        print!("{tree}");
+    }
+
+    let result = match Score(strategy.score(Position::START)) {
+        Score::WINNING => "win for X",
+        Score::LOSING => "win for O",
+        _ => "draw",
+    };
+    println!(
+        "{} keeps the {result} with {} decisions as a tree, {} distinct positions after merging symmetries",
+        letter(options.x),
+        strategy.size(Position::START),
+        seen.len()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tictactoe::board::parse_square;
+
+    /// Replays every line of the tree, checking each move is legal where it's printed
+    fn replay(side: bool) {
+        let mut strategy = Strategy {
+            engine: Engine::new(),
+            side,
+            sizes: HashMap::new(),
+        };
+        let mut tree = String::new();
+        strategy.print_tree(Position::START, 0, &mut tree);
+
+        // The position before each line, by depth
+        let mut stack = vec![Position::START];
+        for line in tree.lines() {
+            let depth = (line.len() - line.trim_start().len()) / 2;
+            stack.truncate(depth + 1);
+            let mut position = stack[depth];
+            let squares = line.trim_start().split([' ', ':']).filter_map(parse_square);
+            for index in squares {
+                assert!(
+                    position.moves().get(index),
+                    "{line:?} plays an occupied square"
+                );
+                position = position.play(index);
+            }
+            stack.push(position);
+        }
+        assert!(stack.len() > 1);
+    }
+
+    #[test]
+    fn tree_moves_are_legal() {
+        replay(true);
+        replay(false);
+    }
+
+    /// Smallest tree from `position` trying every optimal move, without merging symmetries
+    fn smallest(
+        strategy: &mut Strategy,
+        position: Position,
+        sizes: &mut HashMap<usize, u32>,
+    ) -> u32 {
+        if position.is_over() {
+            return 0;
+        }
+        if let Some(size) = sizes.get(&position.board.index()) {
+            return *size;
+        }
+        let best = strategy.score(position);
+        let mut moves = position.moves();
+        let mut children = Vec::new();
+        while moves.count() != 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            children.push(position.play(moves.pop() as u8));
+        }
+        let size = if position.x_to_move == strategy.side {
+            let optimal: Vec<Position> = children
+                .into_iter()
+                .filter(|child| -strategy.score(*child) == best)
+                .collect();
+            1 + optimal
+                .into_iter()
+                .map(|child| smallest(strategy, child, sizes))
+                .min()
+                .unwrap()
+        } else {
+            children
+                .into_iter()
+                .map(|child| smallest(strategy, child, sizes))
+                .sum()
+        };
+        sizes.insert(position.board.index(), size);
+        size
+    }
+
+    #[test]
+    fn reports_the_size_of_the_smallest_printed_tree() {
+        for side in [true, false] {
+            let mut strategy = Strategy {
+                engine: Engine::new(),
+                side,
+                sizes: HashMap::new(),
+            };
+            let mut tree = String::new();
+            strategy.print_tree(Position::START, 0, &mut tree);
+            let size = strategy.size(Position::START);
+            let decisions = tree
+                .lines()
+                .filter(|line| !line.ends_with("ends the game"))
+                .count();
+            assert_eq!(decisions, size as usize);
+            assert_eq!(
+                size,
+                smallest(&mut strategy, Position::START, &mut HashMap::new())
+            );
+        }
+    }
+}
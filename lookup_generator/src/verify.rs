@@ -0,0 +1,114 @@
+use tictactoe::board::Board;
+use tictactoe::evaluation::lookup;
+use tictactoe::position::Position;
+
+use crate::engine::{Engine, Score, LOOKUP_SIZE};
+use crate::retrograde::Table;
+
+/// Turns a score for the side to move into the compiled table's convention
+const fn for_x(score: Score, x_to_move: bool) -> Option<i8> {
+    let value = match score {
+        Score::WINNING => 1,
+        Score::LOSING => -1,
+        Score::DRAWING => 0,
+        _ => return None,
+    };
+    Some(if x_to_move { value } else { -value })
+}
+
+const fn describe(value: Option<i8>) -> &'static str {
+    match value {
+        Some(1) => "X wins",
+        Some(-1) => "O wins",
+        Some(0) => "draw",
+        _ => "missing",
+    }
+}
+
+/// Every legal position of a game that `x_moved_first` or not
+fn positions(x_moved_first: bool) -> impl Iterator<Item = Position> {
+    (0..LOOKUP_SIZE).filter_map(move |index| {
+        let board = Board::from_index(index);
+        // A game O started is one X started with the marks swapped
+        let (first, second) = if x_moved_first {
+            (board.x, board.o)
+        } else {
+            (board.o, board.x)
+        };
+        Board {
+            x: first,
+            o: second,
+        }
+        .is_legal()
+        .then(|| Position {
+            board,
+            x_to_move: x_moved_first == (first.count() == second.count()),
+        })
+    })
+}
+
+/// Compare `Engine::search`, the retrograde table and the compiled `evaluate` on every legal
+/// position, with either side moving first. Returns the number of positions and of mismatches
+fn check(table: &Table, x_moved_first: bool) -> (u32, u32) {
+    // The engine only keys positions by board, so each side moving first needs its own
+    let mut engine = Engine::new();
+    let (mut positions, mut mismatches) = (0, 0);
+    for position in self::positions(x_moved_first) {
+        positions += 1;
+        let Position { board, x_to_move } = position;
+        let searched = for_x(engine.search(board, x_to_move), x_to_move);
+        let retrograde = table
+            .get_position(position)
+            .and_then(|entry| for_x(entry.score, x_to_move));
+        let compiled = lookup(position);
+
+        if searched != retrograde || searched != compiled {
+            mismatches += 1;
+            println!(
+                "{} with {} to move: search says {}, retrograde says {}, evaluate says {}",
+                board.notation(),
+                if x_to_move { 'X' } else { 'O' },
+                describe(searched),
+                describe(retrograde),
+                describe(compiled)
+            );
+        }
+    }
+    (positions, mismatches)
+}
+
+/// Compare `Engine::search`, the retrograde table and the compiled `evaluate` on every legal position.
+/// Returns whether they all agree
+pub fn run() -> bool {
+    let table = Table::solve();
+    let (x_positions, x_mismatches) = check(&table, true);
+    let (o_positions, o_mismatches) = check(&table, false);
+    println!(
+        "Checked {x_positions} legal positions with X moving first and {o_positions} with O moving first, found {} mismatches",
+        x_mismatches + o_mismatches
+    );
+    x_mismatches + o_mismatches == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluators_agree() {
+        let table = Table::solve();
+        assert_eq!(check(&table, true), (5478, 0));
+        assert_eq!(check(&table, false), (5478, 0));
+    }
+
+    #[test]
+    fn positions_have_the_right_side_to_move() {
+        let start = positions(false).find(|position| position.board == Board::EMPTY);
+        assert_eq!(start.map(|position| position.x_to_move), Some(false));
+        let one = positions(false)
+            .find(|position| position.board.occupied().count() == 1)
+            .unwrap();
+        assert_eq!(one.board.o.count(), 1);
+        assert!(one.x_to_move);
+    }
+}
@@ -0,0 +1,183 @@
+use std::io::Write;
+use tictactoe::board::bit_board::BitBoard;
+use tictactoe::controller::GameEvent;
+use tictactoe::evaluation::{distance, outcomes, Outcome};
+
+use crate::clock::format_time;
+use crate::messages::Message;
+use crate::Game;
+
+/// Index of a square said in words, such as `top left`, `centre`, `abajo derecha` or `oben Mitte`.
+/// A single edge word such as `top` means the middle of that edge
+pub fn parse_square_words(input: &str) -> Option<u8> {
+    let (mut row, mut column, mut middles, mut words) = (None, None, 0, 0);
+    let set = |axis: &mut Option<u8>, value| axis.replace(value).is_none().then_some(());
+    let input = input.to_lowercase();
+    for word in input.split([' ', '-']).filter(|word| !word.is_empty()) {
+        match word {
+            "the" | "a" | "la" | "el" | "de" | "del" | "en" | "der" | "in" => continue,
+            "top" | "upper" | "arriba" | "superior" | "oben" => set(&mut row, 2)?,
+            "bottom" | "lower" | "abajo" | "inferior" | "unten" => set(&mut row, 0)?,
+            "left" | "izquierda" | "links" => set(&mut column, 0)?,
+            "right" | "derecha" | "rechts" => set(&mut column, 2)?,
+            "middle" | "centre" | "center" | "centro" | "medio" | "mitte" => middles += 1,
+            _ => return None,
+        }
+        words += 1;
+    }
+    let unset = u8::from(row.is_none()) + u8::from(column.is_none());
+    (words > 0 && middles <= unset).then(|| row.unwrap_or(1) * 3 + column.unwrap_or(1))
+}
+
+impl Game<'_> {
+    /// Such as "top left (a3)", in the input scheme
+    fn spoken_name(&self, index: u8) -> String {
+        format!(
+            "{} ({})",
+            self.text(&Message::Place(index)),
+            self.renderer.scheme.name(index)
+        )
+    }
+
+    /// The board row by row in words, from the top. With a heatmap, empty squares say what
+    /// taking them leads to, with the plies until the end if `distances`
+    fn describe_rows(&mut self, heatmap: Option<[Option<Outcome>; 9]>, distances: bool) {
+        let scheme = self.renderer.scheme;
+        for row in (0..3).rev() {
+            let squares: Vec<String> = (0..3)
+                .map(|column| {
+                    let index = row * 3 + column;
+                    if self.controller.position().board.x.get(index) {
+                        String::from("X")
+                    } else if self.controller.position().board.o.get(index) {
+                        String::from("O")
+                    } else if let Some(outcome) =
+                        heatmap.and_then(|heatmap| heatmap[index as usize])
+                    {
+                        self.text(&Message::Leads(
+                            outcome.result,
+                            distances.then_some(outcome.distance),
+                        ))
+                    } else if scheme.numbers_squares() {
+                        format!(
+                            "{} {}",
+                            self.text(&Message::EmptySquare),
+                            scheme.name(index)
+                        )
+                    } else {
+                        self.text(&Message::EmptySquare)
+                    }
+                })
+                .collect();
+            let label = Some(scheme.row_label(row)).filter(|label| *label != ' ');
+            self.say(&Message::Row(label, row, &squares.join(", ")));
+        }
+    }
+
+    /// Says the moves played, taken back or cleared away and the squares each threatens to win on
+    pub fn announce(&mut self, events: &[GameEvent]) {
+        for event in events {
+            match *event {
+                GameEvent::MovePlayed { x, square } => {
+                    self.say(&Message::Takes(x, &self.spoken_name(square)));
+                }
+                GameEvent::Threat { x, mut squares } => {
+                    let mut names = Vec::new();
+                    while squares != BitBoard::EMPTY {
+                        #[allow(clippy::cast_possible_truncation)]
+                        names.push(self.spoken_name(squares.pop() as u8));
+                    }
+                    self.say(&Message::Threatens(x, &names));
+                }
+                GameEvent::MovesUndone { count } => self.say(&Message::MovesUndone(count)),
+                GameEvent::BoardReset { x_moves_first } => {
+                    self.say(&Message::BoardReset(x_moves_first));
+                }
+                GameEvent::GameOver { .. } | GameEvent::Evaluation { .. } => {}
+            }
+        }
+    }
+
+    /// [`Game::show`] in words, one sentence per line
+    pub fn show_accessible(&mut self) {
+        self.describe_rows(None, false);
+
+        if let Some(message) = self.win_message() {
+            writeln!(self.stdout, "{message}").unwrap();
+        } else if self.controller.position().board.occupied() == BitBoard::FULL {
+            self.say(&Message::Draw);
+        } else if !self.controller.is_agent_turn() {
+            let to_move = self.text(&Message::ToMove(self.controller.position().x_to_move));
+            writeln!(self.stdout, "{to_move}.").unwrap();
+            let winner = match self.evaluate_for_x() {
+                1 => Some(true),
+                -1 => Some(false),
+                _ => None,
+            };
+            self.say(&Message::Outlook(
+                winner,
+                distance(self.controller.position()),
+            ));
+        }
+        if let Some(clock) = &self.clock {
+            let (x, o) = (
+                format_time(clock.remaining(true)),
+                format_time(clock.remaining(false)),
+            );
+            self.say(&Message::TimeLeft(&x, &o));
+        }
+    }
+
+    /// [`Game::show_map`] in words
+    pub fn show_map_accessible(&mut self, distances: bool) {
+        self.say(&Message::WordsLegend(self.controller.position().x_to_move));
+        self.describe_rows(Some(outcomes(self.controller.position())), distances);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tictactoe::board::render::BoardRenderer;
+    use tictactoe::board::Board;
+
+    use crate::messages::Language;
+    use crate::tests::game;
+
+    #[test]
+    fn reads_squares_in_words() {
+        assert_eq!(parse_square_words("top left"), Some(6));
+        assert_eq!(parse_square_words("the Centre"), Some(4));
+        assert_eq!(parse_square_words("middle-right"), Some(5));
+        assert_eq!(parse_square_words("bottom"), Some(1));
+        assert_eq!(parse_square_words("abajo a la derecha"), Some(2));
+        assert_eq!(parse_square_words("oben Mitte"), Some(7));
+        for input in [
+            "",
+            "the",
+            "top bottom",
+            "left right",
+            "middle middle middle",
+            "top b2",
+        ] {
+            assert_eq!(parse_square_words(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn says_the_game_in_words() {
+        let mut output = Vec::new();
+        let mut game = game("centre\na1\nundo\n", &mut output);
+        game.accessible = true;
+        game.run();
+        drop(game);
+        let output = String::from_utf8(output).unwrap();
+        let english = |message: Message| message.text(Language::English);
+        let centre = format!("{} (b2)", english(Message::Place(4)));
+        assert!(output.contains(&english(Message::Takes(true, &centre))));
+        let corner = format!("{} (a1)", english(Message::Place(0)));
+        assert!(output.contains(&english(Message::Takes(false, &corner))));
+        assert!(output.contains(&english(Message::MovesUndone(1))));
+        assert!(!output.contains(&BoardRenderer::ASCII.render(&Board::EMPTY)));
+    }
+}
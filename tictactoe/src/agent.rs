@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+use crate::evaluation::perfect_moves;
+use crate::mcts;
+use crate::position::Position;
+use crate::random::Random;
+
+pub const MAX_LEVEL: u8 = 10;
+
+/// A computer player
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Agent {
+    /// Plays from the solved table, winning as fast and losing as slowly as it can.
+    /// At `level` below [`MAX_LEVEL`] each move has a `1 - level / MAX_LEVEL` chance
+    /// of being random instead
+    Perfect {
+        level: u8,
+    },
+    Random,
+    /// Monte Carlo tree search
+    Mcts {
+        iterations: u32,
+    },
+}
+
+impl Agent {
+    /// Looks up an agent by name, with `level` from 1 to [`MAX_LEVEL`]
+    #[must_use]
+    pub fn from_name(name: &str, level: u8) -> Option<Self> {
+        Some(match name {
+            "perfect" => Self::Perfect { level },
+            "random" => Self::Random,
+            "mcts" => Self::Mcts {
+                iterations: 50 * u32::from(level) * u32::from(level),
+            },
+            _ => return None,
+        })
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Perfect { .. } => "perfect",
+            Self::Random => "random",
+            Self::Mcts { .. } => "mcts",
+        }
+    }
+
+    /// The square this agent takes. The game mustn't be over
+    pub fn choose(self, position: Position, random: &mut Random) -> u8 {
+        match self {
+            Self::Perfect { level } if random.below(u32::from(MAX_LEVEL)) < u32::from(level) => {
+                random.pick(perfect_moves(position))
+            }
+            Self::Perfect { .. } | Self::Random => random.pick(position.moves()),
+            Self::Mcts { iterations } => mcts::search(position, iterations, random),
+        }
+    }
+
+    /// Like [`Agent::choose`], but giving up thinking once `budget` has passed
+    pub fn choose_within(self, position: Position, budget: Duration, random: &mut Random) -> u8 {
+        match self {
+            Self::Mcts { iterations } => {
+                mcts::search_until(position, iterations, Some(Instant::now() + budget), random)
+            }
+            _ => self.choose(position, random),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+    use crate::evaluation::best_moves;
+
+    #[test]
+    fn looks_up_agents_by_name() {
+        for name in ["perfect", "random", "mcts"] {
+            assert_eq!(Agent::from_name(name, 3).map(Agent::name), Some(name));
+        }
+        assert!(Agent::from_name("mcts", 2) == Some(Agent::Mcts { iterations: 200 }));
+        assert!(Agent::from_name("minimax", 3).is_none());
+    }
+
+    #[test]
+    fn every_agent_plays_legal_moves() {
+        let mut random = Random::new(5);
+        let position = Position::new("XO-/-X-/O--".parse::<Board>().unwrap());
+        for agent in [
+            Agent::Perfect { level: 1 },
+            Agent::Random,
+            Agent::Mcts { iterations: 20 },
+        ] {
+            for _ in 0..20 {
+                assert!(position.moves().get(agent.choose(position, &mut random)));
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_play_keeps_the_result() {
+        let mut random = Random::new(5);
+        let agent = Agent::Perfect { level: MAX_LEVEL };
+        let mut position = Position::START;
+        while !position.is_over() {
+            let index = agent.choose_within(position, Duration::ZERO, &mut random);
+            assert!(best_moves(position).get(index));
+            position = position.play(index);
+        }
+        assert_eq!(position.winner(), None);
+    }
+
+    #[test]
+    fn perfect_play_wins_at_once() {
+        let mut random = Random::new(5);
+        let agent = Agent::Perfect { level: MAX_LEVEL };
+        // After a1 b1 b2 c1, a2, c2 and a3 would all still win, but c3 wins now
+        let position = Position::new("---/-X-/XOO".parse::<Board>().unwrap());
+        for _ in 0..20 {
+            assert_eq!(agent.choose(position, &mut random), 8);
+        }
+    }
+}
@@ -1,9 +1,10 @@
+use crate::board::line::Line;
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Shl, Shr};
 
 /// `BitBoard` is a u16, but only 9 bits are used.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct BitBoard(u16);
 
 impl Display for BitBoard {
@@ -86,7 +87,7 @@ impl BitBoard {
         self.0 as usize
     }
 
-    pub fn set(&mut self, index: u8) {
+    pub const fn set(&mut self, index: u8) {
         self.0 |= 1 << index;
     }
 
@@ -111,12 +112,38 @@ impl BitBoard {
     }
 
     #[must_use]
-    pub fn pop(&mut self) -> u32 {
+    pub const fn pop(&mut self) -> u32 {
         let index = self.first();
         self.0 &= self.0 - 1;
         index
     }
 
+    /// Moves every bit to the index given by `source`: bit `i` of the result is bit `source[i]` of `self`
+    #[must_use]
+    const fn permute(self, source: &[u8; 9]) -> Self {
+        let mut result = 0;
+        let mut index = 0;
+        while index < 9 {
+            if self.get(source[index]) {
+                result |= 1 << index;
+            }
+            index += 1;
+        }
+        Self(result)
+    }
+
+    /// Rotates the board a quarter turn
+    #[must_use]
+    pub const fn rotate(&self) -> Self {
+        self.permute(&[6, 3, 0, 7, 4, 1, 8, 5, 2])
+    }
+
+    /// Mirrors the board left to right
+    #[must_use]
+    pub const fn mirror(&self) -> Self {
+        self.permute(&[2, 1, 0, 5, 4, 3, 8, 7, 6])
+    }
+
     #[must_use]
     pub const fn has_won(&self) -> bool {
         self.contains(&Self::BOTTOM_ROW)
@@ -128,6 +155,14 @@ impl BitBoard {
             || self.contains(&Self::TOP_LEFT_DIAGONAL)
             || self.contains(&Self::TOP_RIGHT_DIAGONAL)
     }
+
+    /// Every line this board has all three squares of
+    pub fn winning_lines(&self) -> impl Iterator<Item = Line> {
+        let bit_board = *self;
+        Line::ALL
+            .into_iter()
+            .filter(move |line| bit_board.contains(&line.bit_board()))
+    }
 }
 
 macro_rules! implement_op {
@@ -0,0 +1,63 @@
+use crate::board::bit_board::BitBoard;
+
+/// One of the 8 ways to get three in a row
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Line {
+    /// Row 1 to 3, counted from 0
+    Row(u8),
+    /// Column a to c, counted from 0
+    Column(u8),
+    /// From a1 to c3
+    Diagonal,
+    /// From a3 to c1
+    AntiDiagonal,
+}
+
+impl Line {
+    pub const ALL: [Self; 8] = [
+        Self::Row(0),
+        Self::Row(1),
+        Self::Row(2),
+        Self::Column(0),
+        Self::Column(1),
+        Self::Column(2),
+        Self::Diagonal,
+        Self::AntiDiagonal,
+    ];
+
+    /// The three square indices, from the a or 1 end
+    #[must_use]
+    pub const fn squares(self) -> [u8; 3] {
+        match self {
+            Self::Row(row) => [row * 3, row * 3 + 1, row * 3 + 2],
+            Self::Column(column) => [column, column + 3, column + 6],
+            Self::Diagonal => [0, 4, 8],
+            Self::AntiDiagonal => [6, 4, 2],
+        }
+    }
+
+    #[must_use]
+    pub const fn bit_board(self) -> BitBoard {
+        let [first, second, third] = self.squares();
+        BitBoard::new(1 << first | 1 << second | 1 << third)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn covers_every_line_once() {
+        let mut seen = Vec::new();
+        for line in Line::ALL {
+            let bit_board = line.bit_board();
+            assert_eq!(bit_board.count(), 3);
+            assert!(bit_board.has_won());
+            assert!(!seen.contains(&bit_board));
+            seen.push(bit_board);
+        }
+        assert_eq!(Line::Column(1).squares(), [1, 4, 7]);
+        assert_eq!(Line::AntiDiagonal.squares(), [6, 4, 2]);
+    }
+}
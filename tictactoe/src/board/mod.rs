@@ -1,12 +1,17 @@
 use crate::board::bit_board::BitBoard;
+use crate::board::render::BoardRenderer;
 use core::fmt::Display;
 use core::fmt::Formatter;
+use core::str::FromStr;
 
 #[allow(clippy::module_name_repetitions)]
 pub mod bit_board;
+pub mod line;
+pub mod render;
+pub mod scheme;
 
 /// Board uses two `BitBoards`: one for X, one for O
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Board {
     pub x: BitBoard,
     pub o: BitBoard,
@@ -14,27 +19,83 @@ pub struct Board {
 
 impl Display for Board {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        for row in (0..3).rev() {
-            match row {
-                0 => write!(f, "1 |")?,
-                1 => write!(f, "2 |")?,
-                2 => write!(f, "3 |")?,
-                _ => unreachable!(),
-            };
-            for column in 0..3 {
-                if self.x.get(row * 3 + column) {
-                    write!(f, "X")?;
-                } else if self.o.get(row * 3 + column) {
-                    write!(f, "O")?;
-                } else {
-                    write!(f, "-")?;
-                };
-                write!(f, "|")?;
+        BoardRenderer::ASCII.write(f, self)
+    }
+}
+
+/// Name of a square index, such as `b2`
+#[must_use]
+pub fn square_name(index: u8) -> String {
+    format!("{}{}", (b'a' + index % 3) as char, index / 3 + 1)
+}
+
+/// Index of a square name such as `b2`
+#[must_use]
+pub fn parse_square(name: &str) -> Option<u8> {
+    let mut chars = name.chars();
+
+    let column = match chars.next() {
+        Some('a') => 0,
+        Some('b') => 1,
+        Some('c') => 2,
+        _ => return None,
+    };
+
+    let row = match chars.next() {
+        Some('1') => 0,
+        Some('2') => 1,
+        Some('3') => 2,
+        _ => return None,
+    };
+
+    if chars.next().is_some() {
+        return None;
+    }
+    Some(row * 3 + column)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBoardError {
+    /// Notation didn't have exactly 9 squares
+    Length,
+    /// A square wasn't `X`, `O` or `-`
+    Character(char),
+}
+
+impl Display for ParseBoardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Length => write!(f, "expected 9 squares"),
+            Self::Character(character) => write!(f, "unexpected character {character:?}"),
+        }
+    }
+}
+
+/// Parses notation such as `X-O/-X-/--O`: rows from 3 down to 1, each from a to c.
+/// The slashes are optional
+impl FromStr for Board {
+    type Err = ParseBoardError;
+
+    fn from_str(notation: &str) -> Result<Self, Self::Err> {
+        let mut board = Self::EMPTY;
+        let mut squares = 0;
+        for character in notation.chars().filter(|character| *character != '/') {
+            if squares == 9 {
+                return Err(ParseBoardError::Length);
+            }
+            let index = (2 - squares / 3) * 3 + squares % 3;
+            match character {
+                'X' | 'x' => board.x.set(index),
+                'O' | 'o' => board.o.set(index),
+                '-' | '.' => {}
+                _ => return Err(ParseBoardError::Character(character)),
             }
-            writeln!(f)?;
+            squares += 1;
         }
-        writeln!(f, "   a b c")?;
-        Ok(())
+        if squares != 9 {
+            return Err(ParseBoardError::Length);
+        }
+        Ok(board)
     }
 }
 
@@ -43,4 +104,188 @@ impl Board {
         x: BitBoard::EMPTY,
         o: BitBoard::EMPTY,
     };
+
+    /// Packs both `BitBoards` into one number: X in the low 9 bits, O in the next 9
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.x.as_usize() | self.o.as_usize() << 9
+    }
+
+    /// Inverse of [`Board::index`]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_index(index: usize) -> Self {
+        Self {
+            x: BitBoard::new((index & 0b111_111_111) as u16),
+            o: BitBoard::new((index >> 9) as u16),
+        }
+    }
+
+    /// Inverse of [`Board::from_str`]
+    #[must_use]
+    pub fn notation(&self) -> String {
+        let mut notation = String::new();
+        for row in (0..3).rev() {
+            for column in 0..3 {
+                notation.push(if self.x.get(row * 3 + column) {
+                    'X'
+                } else if self.o.get(row * 3 + column) {
+                    'O'
+                } else {
+                    '-'
+                });
+            }
+            if row != 0 {
+                notation.push('/');
+            }
+        }
+        notation
+    }
+
+    /// Whether the board can come up in a game where X moves first:
+    /// no square is taken twice, the turns alternate, and play stopped at the first line
+    #[must_use]
+    pub const fn is_legal(&self) -> bool {
+        if self.x.as_usize() & self.o.as_usize() != 0 {
+            return false;
+        }
+        let (x, o) = (self.x.count(), self.o.count());
+        match (self.x.has_won(), self.o.has_won()) {
+            (false, false) => x == o || x == o + 1,
+            (true, false) => x == o + 1,
+            (false, true) => x == o,
+            (true, true) => false,
+        }
+    }
+
+    /// Squares taken by either side
+    #[must_use]
+    pub fn occupied(&self) -> BitBoard {
+        self.x | self.o
+    }
+
+    /// The 8 rotations and reflections of the board, starting with itself
+    #[must_use]
+    pub const fn symmetries(&self) -> [Self; 8] {
+        let mut symmetries = [*self; 8];
+        let mut index = 1;
+        while index < 8 {
+            let previous = symmetries[index - 1];
+            symmetries[index] = if index == 4 {
+                Self {
+                    x: previous.x.mirror(),
+                    o: previous.o.mirror(),
+                }
+            } else {
+                Self {
+                    x: previous.x.rotate(),
+                    o: previous.o.rotate(),
+                }
+            };
+            index += 1;
+        }
+        symmetries
+    }
+
+    /// The symmetry with the lowest index, shared by every board it can be rotated or reflected into
+    #[must_use]
+    pub const fn canonical(&self) -> Self {
+        let symmetries = self.symmetries();
+        let mut best = symmetries[0];
+        let mut index = 1;
+        while index < 8 {
+            if symmetries[index].index() < best.index() {
+                best = symmetries[index];
+            }
+            index += 1;
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_boards() {
+        let board = Board {
+            x: BitBoard::new(0b100_010_001),
+            o: BitBoard::new(0b000_001_010),
+        };
+        assert!(Board::from_index(board.index()) == board);
+        assert_eq!(Board::EMPTY.index(), 0);
+    }
+
+    #[test]
+    fn finds_symmetries() {
+        // X in the a1 corner, O on the b1 edge
+        let board = Board {
+            x: BitBoard::new(1 << 0),
+            o: BitBoard::new(1 << 1),
+        };
+        let symmetries = board.symmetries();
+        assert!(symmetries[0] == board);
+        for (index, symmetry) in symmetries.iter().enumerate() {
+            // Corners stay corners and edges stay edges, and no two symmetries agree
+            assert!(matches!(symmetry.x.first(), 0 | 2 | 6 | 8));
+            assert!(matches!(symmetry.o.first(), 1 | 3 | 5 | 7));
+            assert!(symmetries[..index].iter().all(|other| other != symmetry));
+            assert!(symmetry.canonical() == board.canonical());
+        }
+        assert_eq!(BitBoard::new(1 << 0).rotate(), BitBoard::new(1 << 2));
+        assert_eq!(BitBoard::new(1 << 0).mirror(), BitBoard::new(1 << 2));
+    }
+
+    #[test]
+    fn reads_and_writes_notation() {
+        let board: Board = "X-O/-x-/..o".parse().unwrap();
+        assert!(board.x.get(6) && board.x.get(4) && board.o.get(8) && board.o.get(2));
+        assert_eq!(board.notation(), "X-O/-X-/--O");
+        assert!(board.notation().parse::<Board>() == Ok(board));
+        assert!("XO-------".parse::<Board>().is_ok());
+        assert_eq!(
+            "X-O/-X-".parse::<Board>().err(),
+            Some(ParseBoardError::Length)
+        );
+        assert_eq!(
+            "X-O/-X-/--O/-".parse::<Board>().err(),
+            Some(ParseBoardError::Length)
+        );
+        assert_eq!(
+            "X-O/-Y-/--O".parse::<Board>().err(),
+            Some(ParseBoardError::Character('Y'))
+        );
+    }
+
+    #[test]
+    fn names_squares() {
+        for index in 0..9 {
+            assert_eq!(parse_square(&square_name(index)), Some(index));
+        }
+        assert_eq!(square_name(5), "c2");
+        for name in ["", "b", "d1", "a4", "b22", "B2"] {
+            assert_eq!(parse_square(name), None, "{name:?}");
+        }
+    }
+
+    #[test]
+    fn knows_which_boards_come_up_in_a_game() {
+        let legal = |notation: &str| notation.parse::<Board>().unwrap().is_legal();
+        assert!(legal("---/---/---"));
+        assert!(legal("---/-X-/---"));
+        assert!(legal("XXX/OO-/---"));
+        assert!(legal("XX-/OOO/X--"));
+        // O can't have moved first, or twice in a row
+        assert!(!legal("---/-O-/---"));
+        assert!(!legal("XXX/---/---"));
+        // Play stops at the first line
+        assert!(!legal("XXX/OO-/--O"));
+        assert!(!legal("XXX/OOO/---"));
+        let overlapping = Board {
+            x: BitBoard::new(1),
+            o: BitBoard::new(1),
+        };
+        assert!(!overlapping.is_legal());
+    }
 }
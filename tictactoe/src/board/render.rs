@@ -0,0 +1,285 @@
+use crate::board::bit_board::BitBoard;
+use crate::board::scheme::Scheme;
+use crate::board::Board;
+use crate::evaluation::Outcome;
+use core::fmt::Write;
+use std::io::IsTerminal;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// `X|O|-` with pipes, as printed by `Board`'s `Display`
+    Ascii,
+    /// Box-drawing characters
+    Unicode,
+}
+
+const X_COLOUR: &str = "\x1b[1;31m";
+const O_COLOUR: &str = "\x1b[1;34m";
+const HIGHLIGHT: &str = "\x1b[7m";
+const WINNING: &str = "\x1b[42m";
+const WIN_COLOUR: &str = "\x1b[32m";
+const DRAW_COLOUR: &str = "\x1b[33m";
+const LOSS_COLOUR: &str = "\x1b[35m";
+const NUMBER_COLOUR: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+/// Draws a `Board` as text
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BoardRenderer {
+    pub style: Style,
+    /// Colour the marks with ANSI escape codes
+    pub colour: bool,
+    /// Square drawn highlighted when colour is on, usually the last move
+    pub last_move: Option<u8>,
+    /// Squares drawn on a green background when colour is on, such as a winning line
+    pub winning: BitBoard,
+    /// Square drawn in reverse video even when colour is off, such as a cursor
+    pub cursor: Option<u8>,
+    /// What taking each empty square leads to, drawn in it as W, D or L
+    pub heatmap: [Option<Outcome>; 9],
+    /// Follow each heatmap letter with its distance to the end of the game
+    pub distances: bool,
+    /// Labels the rows, columns or empty squares the way squares are typed
+    pub scheme: Scheme,
+}
+
+/// Such as `W3`, coloured green, yellow or magenta
+fn outcome_label(outcome: Outcome, distance: bool) -> (String, &'static str) {
+    let (letter, colour) = match outcome.result {
+        1 => ('W', WIN_COLOUR),
+        -1 => ('L', LOSS_COLOUR),
+        _ => ('D', DRAW_COLOUR),
+    };
+    let label = if distance {
+        format!("{letter}{}", outcome.distance)
+    } else {
+        letter.to_string()
+    };
+    (label, colour)
+}
+
+impl BoardRenderer {
+    pub const ASCII: Self = Self {
+        style: Style::Ascii,
+        colour: false,
+        last_move: None,
+        winning: BitBoard::EMPTY,
+        cursor: None,
+        heatmap: [None; 9],
+        distances: false,
+        scheme: Scheme::Algebraic,
+    };
+
+    /// Whether colour should be on when nothing was asked for:
+    /// only on a terminal, and never when `NO_COLOR` is set to anything
+    #[must_use]
+    pub fn colour_by_default() -> bool {
+        std::io::stdout().is_terminal()
+            && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+    }
+
+    /// Characters inside each cell, between the walls
+    const fn cell_width(&self) -> usize {
+        match self.style {
+            Style::Ascii if self.distances => 2,
+            Style::Ascii => 1,
+            Style::Unicode => 3,
+        }
+    }
+
+    fn write_cell(self, f: &mut impl Write, board: Board, index: u8) -> core::fmt::Result {
+        let (text, colour) = if board.x.get(index) {
+            (String::from("X"), X_COLOUR)
+        } else if board.o.get(index) {
+            (String::from("O"), O_COLOUR)
+        } else if let Some(outcome) = self.heatmap[index as usize] {
+            outcome_label(outcome, self.distances)
+        } else if self.scheme.numbers_squares() {
+            (self.scheme.name(index), NUMBER_COLOUR)
+        } else {
+            (
+                String::from(match self.style {
+                    Style::Ascii => "-",
+                    Style::Unicode => " ",
+                }),
+                "",
+            )
+        };
+        let width = self.cell_width();
+        let padding = " ".repeat((width - text.chars().count()) / 2);
+        let text = format!("{padding}{text}");
+        let text = format!("{text:<width$}");
+
+        let highlight = if self.cursor == Some(index) {
+            HIGHLIGHT
+        } else if !self.colour {
+            ""
+        } else if self.winning.get(index) {
+            WINNING
+        } else if self.last_move == Some(index) {
+            HIGHLIGHT
+        } else {
+            ""
+        };
+        let colour = if self.colour { colour } else { "" };
+        if colour.is_empty() && highlight.is_empty() {
+            return write!(f, "{text}");
+        }
+        write!(f, "{highlight}{colour}{text}{RESET}")
+    }
+
+    /// # Errors
+    /// If `f` fails to write
+    pub fn write(&self, f: &mut impl Write, board: &Board) -> core::fmt::Result {
+        let (top, separator, bottom, wall) = match self.style {
+            Style::Ascii => (None, None, None, '|'),
+            Style::Unicode => (
+                Some("  ┌───┬───┬───┐"),
+                Some("  ├───┼───┼───┤"),
+                Some("  └───┴───┴───┘"),
+                '│',
+            ),
+        };
+        // Each column's label lines up with the middle of its cells
+        let width = self.cell_width();
+        let mut labels = String::from("  ");
+        for column in 0..3 {
+            if let Some(label) = self.scheme.column_label(column) {
+                write!(labels, " {label:^width$}")?;
+            }
+        }
+
+        if let Some(top) = top {
+            writeln!(f, "{top}")?;
+        }
+        for row in (0..3).rev() {
+            write!(f, "{} {wall}", self.scheme.row_label(row))?;
+            for column in 0..3 {
+                self.write_cell(f, *board, row * 3 + column)?;
+                write!(f, "{wall}")?;
+            }
+            writeln!(f)?;
+            if row != 0 {
+                if let Some(separator) = separator {
+                    writeln!(f, "{separator}")?;
+                }
+            }
+        }
+        if let Some(bottom) = bottom {
+            writeln!(f, "{bottom}")?;
+        }
+        let labels = labels.trim_end();
+        if labels.is_empty() {
+            return Ok(());
+        }
+        writeln!(f, "{labels}")
+    }
+
+    /// The square drawn at a column and line of [`BoardRenderer::write`]'s text, counting from 0
+    #[must_use]
+    pub const fn square_at(&self, column: usize, line: usize) -> Option<u8> {
+        let row = match self.style {
+            Style::Ascii => line,
+            // Borders and separators take the even lines
+            Style::Unicode if line % 2 == 1 => line / 2,
+            Style::Unicode => return None,
+        };
+        // Each row starts with its label, a space and a wall, and each cell ends with a wall
+        let width = self.cell_width();
+        if row > 2 || column < 3 || (column - 3) % (width + 1) == width {
+            return None;
+        }
+        let cell = (column - 3) / (width + 1);
+        if cell > 2 {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        Some(((2 - row) * 3 + cell) as u8)
+    }
+
+    #[must_use]
+    pub fn render(&self, board: &Board) -> String {
+        let mut text = String::new();
+        // Writing to a `String` can't fail
+        let _ = self.write(&mut text, board);
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Line and column of the only `X` in the text
+    fn find_x(text: &str) -> (usize, usize) {
+        text.lines()
+            .enumerate()
+            .find_map(|(line, text)| Some((line, text.chars().position(|c| c == 'X')?)))
+            .unwrap()
+    }
+
+    #[test]
+    fn finds_squares_where_they_are_drawn() {
+        for style in [Style::Ascii, Style::Unicode] {
+            for distances in [false, true] {
+                let renderer = BoardRenderer {
+                    style,
+                    distances,
+                    ..BoardRenderer::ASCII
+                };
+                for index in 0..9 {
+                    let mut board = Board::EMPTY;
+                    board.x.set(index);
+                    let (line, column) = find_x(&renderer.render(&board));
+                    assert_eq!(renderer.square_at(column, line), Some(index));
+                }
+                assert_eq!(renderer.square_at(0, 0), None);
+                assert_eq!(renderer.square_at(2, 1), None);
+                assert_eq!(renderer.square_at(3, 9), None);
+                assert_eq!(renderer.square_at(40, 1), None);
+            }
+        }
+    }
+
+    #[test]
+    fn shows_the_cursor_without_colour() {
+        let board: Board = "---/-X-/---".parse().unwrap();
+        let renderer = BoardRenderer {
+            last_move: Some(4),
+            winning: BitBoard::new(1 << 4),
+            cursor: Some(0),
+            ..BoardRenderer::ASCII
+        };
+        assert_eq!(
+            renderer.render(&board),
+            "3 |-|-|-|\n2 |-|X|-|\n1 |\x1b[7m-\x1b[0m|-|-|\n   a b c\n"
+        );
+        let coloured = BoardRenderer {
+            colour: true,
+            ..renderer
+        }
+        .render(&board);
+        assert!(coloured.contains("\x1b[42m\x1b[1;31mX\x1b[0m"));
+        assert!(coloured.contains("\x1b[7m-\x1b[0m"));
+    }
+
+    #[test]
+    fn labels_match_the_scheme() {
+        let renderer = |scheme| BoardRenderer {
+            scheme,
+            ..BoardRenderer::ASCII
+        };
+        assert_eq!(
+            renderer(Scheme::Algebraic).render(&Board::EMPTY),
+            "3 |-|-|-|\n2 |-|-|-|\n1 |-|-|-|\n   a b c\n"
+        );
+        assert_eq!(
+            renderer(Scheme::Numpad).render(&Board::EMPTY),
+            "  |7|8|9|\n  |4|5|6|\n  |1|2|3|\n"
+        );
+        assert_eq!(
+            renderer(Scheme::RowColumn).render(&Board::EMPTY),
+            "1 |-|-|-|\n2 |-|-|-|\n3 |-|-|-|\n   1 2 3\n"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::board::bit_board::BitBoard;
+use crate::board::{parse_square, square_name};
+
+/// How squares are typed, and how the board labels them to match
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Scheme {
+    /// `b2`, also accepting `B2` and `2b`
+    Algebraic,
+    /// `1` to `9` laid out like a numeric keypad, with 7, 8 and 9 on the top row
+    Numpad,
+    /// `1` to `9` in reading order, from the top left
+    Reading,
+    /// `row,column`, each from 1 to 3, counting rows from the top
+    RowColumn,
+}
+
+impl Scheme {
+    /// Looks up a scheme by the name used on the command line
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "algebraic" => Self::Algebraic,
+            "numpad" => Self::Numpad,
+            "reading" => Self::Reading,
+            "rowcol" => Self::RowColumn,
+            _ => return None,
+        })
+    }
+
+    /// Index of a square typed in this scheme
+    #[must_use]
+    pub fn parse(self, input: &str) -> Option<u8> {
+        let input = input.trim().to_ascii_lowercase();
+        match self {
+            Self::Algebraic => parse_square(&input).or_else(|| {
+                let mut chars = input.chars();
+                let (row, column) = (chars.next()?, chars.next()?);
+                parse_square(&format!("{column}{row}")).filter(|_| chars.next().is_none())
+            }),
+            Self::Numpad | Self::Reading => {
+                let digit = match input.as_bytes() {
+                    [digit @ b'1'..=b'9'] => digit - b'1',
+                    _ => return None,
+                };
+                Some(if self == Self::Numpad {
+                    digit
+                } else {
+                    (2 - digit / 3) * 3 + digit % 3
+                })
+            }
+            Self::RowColumn => {
+                let (row, column) = input.split_once(',')?;
+                let (row, column): (u8, u8) =
+                    (row.trim().parse().ok()?, column.trim().parse().ok()?);
+                ((1..=3).contains(&row) && (1..=3).contains(&column))
+                    .then(|| (3 - row) * 3 + column - 1)
+            }
+        }
+    }
+
+    /// How a square is typed in this scheme, such as `b2`, `5` or `2,2`
+    #[must_use]
+    pub fn name(self, index: u8) -> String {
+        match self {
+            Self::Algebraic => square_name(index),
+            Self::Numpad => (index + 1).to_string(),
+            Self::Reading => ((2 - index / 3) * 3 + index % 3 + 1).to_string(),
+            Self::RowColumn => format!("{},{}", 3 - index / 3, index % 3 + 1),
+        }
+    }
+
+    /// [`Scheme::name`] of every square in `squares`, in index order
+    #[must_use]
+    pub fn names(self, mut squares: BitBoard) -> Vec<String> {
+        let mut names = Vec::new();
+        while squares != BitBoard::EMPTY {
+            #[allow(clippy::cast_possible_truncation)]
+            names.push(self.name(squares.pop() as u8));
+        }
+        names
+    }
+
+    /// Label at the start of a row, with row 0 at the bottom
+    #[must_use]
+    pub const fn row_label(self, row: u8) -> char {
+        match self {
+            Self::Algebraic => (b'1' + row) as char,
+            Self::RowColumn => (b'3' - row) as char,
+            Self::Numpad | Self::Reading => ' ',
+        }
+    }
+
+    /// Label under a column, if the scheme names columns
+    #[must_use]
+    pub const fn column_label(self, column: u8) -> Option<char> {
+        match self {
+            Self::Algebraic => Some((b'a' + column) as char),
+            Self::RowColumn => Some((b'1' + column) as char),
+            Self::Numpad | Self::Reading => None,
+        }
+    }
+
+    /// Whether empty squares show their number, since the rows and columns aren't labelled
+    #[must_use]
+    pub const fn numbers_squares(self) -> bool {
+        matches!(self, Self::Numpad | Self::Reading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMES: [Scheme; 4] = [
+        Scheme::Algebraic,
+        Scheme::Numpad,
+        Scheme::Reading,
+        Scheme::RowColumn,
+    ];
+
+    #[test]
+    fn parses_what_it_names() {
+        for scheme in SCHEMES {
+            for index in 0..9 {
+                assert_eq!(scheme.parse(&scheme.name(index)), Some(index), "{scheme:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn reads_each_scheme() {
+        assert_eq!(Scheme::from_name("rowcol"), Some(Scheme::RowColumn));
+        assert_eq!(Scheme::from_name("qwerty"), None);
+        assert_eq!(Scheme::Algebraic.parse(" C3 "), Some(8));
+        assert_eq!(Scheme::Algebraic.parse("2b"), Some(4));
+        assert_eq!(Scheme::Algebraic.parse("2b1"), None);
+        assert_eq!(Scheme::Numpad.parse("7"), Some(6));
+        assert_eq!(Scheme::Reading.parse("7"), Some(0));
+        assert_eq!(Scheme::Reading.parse("0"), None);
+        assert_eq!(Scheme::RowColumn.parse("1, 3"), Some(8));
+        assert_eq!(Scheme::RowColumn.parse("4,1"), None);
+        assert_eq!(Scheme::RowColumn.parse("b2"), None);
+    }
+
+    #[test]
+    fn names_a_set_of_squares() {
+        let corners = BitBoard::new(0b101_000_101);
+        assert_eq!(Scheme::Algebraic.names(corners), ["a1", "c1", "a3", "c3"]);
+        assert_eq!(Scheme::Numpad.names(corners), ["1", "3", "7", "9"]);
+    }
+
+    #[test]
+    fn labels_rows_and_columns() {
+        assert_eq!(Scheme::Algebraic.row_label(2), '3');
+        assert_eq!(Scheme::RowColumn.row_label(2), '1');
+        assert_eq!(Scheme::Algebraic.column_label(1), Some('b'));
+        assert_eq!(Scheme::RowColumn.column_label(1), Some('2'));
+        assert_eq!(Scheme::Numpad.column_label(1), None);
+        assert!(Scheme::Reading.numbers_squares());
+        assert!(!Scheme::Algebraic.numbers_squares());
+    }
+}
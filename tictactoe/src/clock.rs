@@ -0,0 +1,228 @@
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy)]
+pub enum TimeControl {
+    /// A starting amount of time, plus an increment after every move
+    Fischer { base: Duration, increment: Duration },
+    /// The same time for every move, nothing carried over
+    PerMove(Duration),
+}
+
+impl TimeControl {
+    /// Parses seconds such as `60+2` for a base and increment, or `60` for no increment
+    pub fn parse_fischer(text: &str) -> Option<Self> {
+        let (base, increment) = text.split_once('+').unwrap_or((text, "0"));
+        Some(Self::Fischer {
+            base: Duration::try_from_secs_f64(base.parse().ok()?).ok()?,
+            increment: Duration::try_from_secs_f64(increment.parse().ok()?).ok()?,
+        })
+    }
+
+    const fn start(self) -> Duration {
+        match self {
+            Self::Fischer { base, .. } => base,
+            Self::PerMove(time) => time,
+        }
+    }
+}
+
+/// Time left for both players, with at most one of them running
+pub struct Clock {
+    pub control: TimeControl,
+    x_remaining: Duration,
+    o_remaining: Duration,
+    /// Side whose time is running, and since when
+    running: Option<(bool, Instant)>,
+}
+
+/// Such as `1:05.3`
+pub fn format_time(time: Duration) -> String {
+    let tenths = time.as_millis() / 100;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+impl Clock {
+    pub const fn new(control: TimeControl) -> Self {
+        Self {
+            control,
+            x_remaining: control.start(),
+            o_remaining: control.start(),
+            running: None,
+        }
+    }
+
+    /// A stopped clock with the time left for each side
+    pub const fn with_remaining(
+        control: TimeControl,
+        x_remaining: Duration,
+        o_remaining: Duration,
+    ) -> Self {
+        Self {
+            control,
+            x_remaining,
+            o_remaining,
+            running: None,
+        }
+    }
+
+    pub const fn reset(&mut self) {
+        *self = Self::new(self.control);
+    }
+
+    const fn stored(&mut self, x: bool) -> &mut Duration {
+        if x {
+            &mut self.x_remaining
+        } else {
+            &mut self.o_remaining
+        }
+    }
+
+    /// Time left for a side, counting down while it runs
+    pub fn remaining(&self, x: bool) -> Duration {
+        let stored = if x {
+            self.x_remaining
+        } else {
+            self.o_remaining
+        };
+        match self.running {
+            Some((side, since)) if side == x => stored.saturating_sub(since.elapsed()),
+            _ => stored,
+        }
+    }
+
+    /// The side whose time is running, if it has run out
+    pub fn fallen(&self) -> Option<bool> {
+        let (side, _) = self.running?;
+        (self.remaining(side) == Duration::ZERO).then_some(side)
+    }
+
+    /// Starts a side's time, unless it's already running
+    pub fn start(&mut self, x: bool) {
+        if self.running.is_some_and(|(side, _)| side == x) {
+            return;
+        }
+        self.pause();
+        if let TimeControl::PerMove(time) = self.control {
+            *self.stored(x) = time;
+        }
+        self.running = Some((x, Instant::now()));
+    }
+
+    /// Stops whichever time is running, without an increment
+    pub fn pause(&mut self) {
+        if let Some((side, since)) = self.running.take() {
+            let remaining = self.stored(side);
+            *remaining = remaining.saturating_sub(since.elapsed());
+        }
+    }
+
+    /// Stops a side's time after it moved. Returns false if its flag fell first
+    pub fn finish_move(&mut self, x: bool) -> bool {
+        let Some((side, since)) = self.running.take() else {
+            return true;
+        };
+        debug_assert_eq!(side, x);
+        let elapsed = since.elapsed();
+        let control = self.control;
+        let remaining = self.stored(x);
+        if elapsed >= *remaining {
+            *remaining = Duration::ZERO;
+            return false;
+        }
+        *remaining = match control {
+            TimeControl::Fischer { increment, .. } => remaining.saturating_sub(elapsed) + increment,
+            TimeControl::PerMove(time) => time,
+        };
+        true
+    }
+
+    /// How long an AI should think, with `moves_left` of its own moves still to play at most
+    pub fn budget(&self, x: bool, moves_left: u32) -> Duration {
+        let remaining = self.remaining(x);
+        match self.control {
+            TimeControl::PerMove(_) => remaining.mul_f64(0.8),
+            TimeControl::Fischer { increment, .. } => {
+                (remaining / moves_left.max(1) + increment.mul_f64(0.8)).min(remaining.mul_f64(0.8))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn fischer(base: u64, increment: u64) -> TimeControl {
+        TimeControl::Fischer {
+            base: Duration::from_secs(base),
+            increment: Duration::from_secs(increment),
+        }
+    }
+
+    #[test]
+    fn parses_and_formats_times() {
+        assert!(matches!(
+            TimeControl::parse_fischer("60+2"),
+            Some(TimeControl::Fischer { base, increment })
+                if base == Duration::from_mins(1) && increment == Duration::from_secs(2)
+        ));
+        assert!(matches!(
+            TimeControl::parse_fischer("1.5"),
+            Some(TimeControl::Fischer { base, increment })
+                if base == Duration::from_millis(1500) && increment == Duration::ZERO
+        ));
+        for text in ["", "x", "-1", "60+", "60+2+1"] {
+            assert!(TimeControl::parse_fischer(text).is_none(), "{text:?}");
+        }
+        assert_eq!(format_time(Duration::from_millis(65_380)), "1:05.3");
+        assert_eq!(format_time(Duration::from_mins(10)), "10:00.0");
+    }
+
+    #[test]
+    fn adds_the_increment_after_a_move() {
+        let mut clock = Clock::new(fischer(60, 2));
+        clock.start(true);
+        assert!(clock.finish_move(true));
+        let remaining = clock.remaining(true);
+        assert!(remaining > Duration::from_secs(61) && remaining <= Duration::from_secs(62));
+        assert_eq!(clock.remaining(false), Duration::from_mins(1));
+        assert_eq!(clock.fallen(), None);
+    }
+
+    #[test]
+    fn flags_fall_when_time_runs_out() {
+        let mut clock = Clock::with_remaining(fischer(60, 2), Duration::ZERO, Duration::ZERO);
+        clock.start(false);
+        assert_eq!(clock.fallen(), Some(false));
+        assert!(!clock.finish_move(false));
+        assert_eq!(clock.remaining(false), Duration::ZERO);
+
+        let mut clock = Clock::new(TimeControl::PerMove(Duration::ZERO));
+        clock.start(true);
+        assert_eq!(clock.fallen(), Some(true));
+    }
+
+    #[test]
+    fn per_move_time_starts_afresh() {
+        let time = Duration::from_secs(5);
+        let mut clock = Clock::with_remaining(TimeControl::PerMove(time), Duration::ZERO, time);
+        // Whatever was left over, the move starts with its full time and gets it back after
+        clock.start(true);
+        assert!(clock.remaining(true) > Duration::from_secs(4));
+        assert!(clock.finish_move(true));
+        assert_eq!(clock.remaining(true), time);
+    }
+
+    #[test]
+    fn budgets_thinking_time() {
+        let clock = Clock::with_remaining(
+            fischer(0, 0),
+            Duration::from_secs(10),
+            Duration::from_secs(1),
+        );
+        assert_eq!(clock.budget(true, 5), Duration::from_secs(2));
+        assert_eq!(clock.budget(false, 0), Duration::from_millis(800));
+        let clock = Clock::new(TimeControl::PerMove(Duration::from_secs(10)));
+        assert_eq!(clock.budget(true, 5), Duration::from_secs(8));
+    }
+}
@@ -0,0 +1,109 @@
+use tictactoe::board::scheme::Scheme;
+
+use crate::accessible::parse_square_words;
+
+/// Something the player typed at the prompt
+#[derive(Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Take the square with this index
+    Play(u8),
+    Undo,
+    Redo,
+    Hint,
+    /// Show what taking each empty square leads to, with the distances to the end if true
+    Map(bool),
+    New,
+    /// Change which side the AI plays
+    Swap,
+    Show,
+    /// Write the game to a file
+    Save(String),
+    /// Continue the game in a file
+    Load(String),
+    Help,
+    Quit,
+}
+
+pub enum ParseCommandError {
+    Empty,
+    /// `save` or `load` without a file name
+    MissingFile(&'static str),
+    Unknown(String),
+}
+
+impl Command {
+    /// Parses a command, or a square typed in `scheme` or said in words
+    pub fn parse(input: &str, scheme: Scheme) -> Result<Self, ParseCommandError> {
+        let input = input.trim();
+        if let Some((command, file)) = input.split_once(char::is_whitespace) {
+            let file = file.trim().to_owned();
+            match command {
+                "save" => return Ok(Self::Save(file)),
+                "load" => return Ok(Self::Load(file)),
+                _ => {}
+            }
+        }
+        Ok(match input {
+            "" => return Err(ParseCommandError::Empty),
+            "undo" => Self::Undo,
+            "redo" => Self::Redo,
+            "hint" => Self::Hint,
+            "map" => Self::Map(false),
+            "map plies" => Self::Map(true),
+            "new" => Self::New,
+            "swap" => Self::Swap,
+            "show" => Self::Show,
+            "save" => return Err(ParseCommandError::MissingFile("save")),
+            "load" => return Err(ParseCommandError::MissingFile("load")),
+            "help" | "?" => Self::Help,
+            "quit" | "exit" => Self::Quit,
+            _ => Self::Play(
+                scheme
+                    .parse(input)
+                    .or_else(|| parse_square_words(input))
+                    .ok_or_else(|| ParseCommandError::Unknown(input.to_owned()))?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Option<Command> {
+        Command::parse(input, Scheme::Algebraic).ok()
+    }
+
+    #[test]
+    fn parses_commands_and_squares() {
+        assert!(parse(" undo ") == Some(Command::Undo));
+        assert!(parse("map plies") == Some(Command::Map(true)));
+        assert!(parse("?") == Some(Command::Help));
+        assert!(parse("exit") == Some(Command::Quit));
+        assert!(parse("save  my game.txt ") == Some(Command::Save("my game.txt".to_owned())));
+        assert!(parse("b2") == Some(Command::Play(4)));
+        assert!(parse("top left") == Some(Command::Play(6)));
+        assert!(Command::parse("7", Scheme::Numpad).ok() == Some(Command::Play(6)));
+    }
+
+    #[test]
+    fn reports_what_went_wrong() {
+        assert!(matches!(
+            Command::parse("  ", Scheme::Algebraic),
+            Err(ParseCommandError::Empty)
+        ));
+        assert!(matches!(
+            Command::parse("load", Scheme::Algebraic),
+            Err(ParseCommandError::MissingFile("load"))
+        ));
+        assert!(matches!(
+            Command::parse("d4", Scheme::Algebraic),
+            Err(ParseCommandError::Unknown(input)) if input == "d4"
+        ));
+        assert!(matches!(
+            Command::parse("5", Scheme::Algebraic),
+            Err(ParseCommandError::Unknown(_))
+        ));
+    }
+}
@@ -0,0 +1,543 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use std::time::Duration;
+
+use crate::agent::Agent;
+use crate::board::bit_board::BitBoard;
+use crate::board::line::Line;
+use crate::board::Board;
+use crate::evaluation::{distance, evaluate};
+use crate::position::Position;
+use crate::random::Random;
+
+/// Something that happened in a game, for a frontend to show
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GameEvent {
+    /// A side took a square
+    MovePlayed { x: bool, square: u8 },
+    /// The side that just moved can win next turn on these squares
+    Threat { x: bool, squares: BitBoard },
+    /// `Some(true)` if X won, `Some(false)` if O did, `None` for a draw
+    GameOver { winner: Option<bool> },
+    /// Who wins with perfect play from here, `None` for a draw, and in how many plies
+    Evaluation { winner: Option<bool>, distance: u8 },
+    /// The last `count` moves were taken back
+    MovesUndone { count: u8 },
+    /// The board was cleared for a game that `x_moves_first` or not. A restored game follows
+    /// with a `MovePlayed` for each of its moves
+    BoardReset { x_moves_first: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    GameOver,
+    /// The square already has a mark
+    Occupied,
+    /// Not a square index from 0 to 8
+    OutOfRange,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::GameOver => write!(f, "the game is over"),
+            Self::Occupied => write!(f, "the square is taken"),
+            Self::OutOfRange => write!(f, "there's no such square"),
+        }
+    }
+}
+
+/// A game's rules, turn order, computer players and history, without any input or output.
+/// Frontends call it with moves and take what happened from [`GameController::events`]
+#[derive(Clone)]
+pub struct GameController {
+    position: Position,
+    x_moves_first: bool,
+    /// `None` for a human
+    x_agent: Option<Agent>,
+    o_agent: Option<Agent>,
+    random: Random,
+    /// Squares played so far, in order
+    history: Vec<u8>,
+    /// Squares taken back with undo, the most recent last
+    undone: Vec<u8>,
+    /// The side that lost without the board deciding it, such as on time
+    forfeited: Option<bool>,
+    events: Vec<GameEvent>,
+}
+
+/// Empty squares that complete a line of `x`'s through `index`
+fn threats(position: Position, x: bool, index: u8) -> BitBoard {
+    let mine = if x {
+        position.board.x
+    } else {
+        position.board.o
+    };
+    let mut threats = BitBoard::EMPTY;
+    for line in Line::ALL {
+        let squares = line.squares();
+        if !squares.contains(&index) {
+            continue;
+        }
+        let marks = squares.iter().filter(|square| mine.get(**square)).count();
+        let empty = squares
+            .iter()
+            .find(|square| !position.board.occupied().get(**square));
+        if let (2, Some(&empty)) = (marks, empty) {
+            threats.set(empty);
+        }
+    }
+    threats
+}
+
+impl GameController {
+    #[must_use]
+    pub const fn new(
+        x_agent: Option<Agent>,
+        o_agent: Option<Agent>,
+        x_moves_first: bool,
+        random: Random,
+    ) -> Self {
+        Self {
+            position: Position {
+                board: Board::EMPTY,
+                x_to_move: x_moves_first,
+            },
+            x_moves_first,
+            x_agent,
+            o_agent,
+            random,
+            history: Vec::new(),
+            undone: Vec::new(),
+            forfeited: None,
+            events: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn position(&self) -> Position {
+        self.position
+    }
+
+    #[must_use]
+    pub const fn x_moves_first(&self) -> bool {
+        self.x_moves_first
+    }
+
+    #[must_use]
+    pub fn history(&self) -> &[u8] {
+        &self.history
+    }
+
+    #[must_use]
+    pub fn undone(&self) -> &[u8] {
+        &self.undone
+    }
+
+    #[must_use]
+    pub const fn random(&self) -> &Random {
+        &self.random
+    }
+
+    /// The computer player of a side, `None` for a human
+    #[must_use]
+    pub const fn agent(&self, x: bool) -> Option<Agent> {
+        if x {
+            self.x_agent
+        } else {
+            self.o_agent
+        }
+    }
+
+    #[must_use]
+    pub const fn agent_to_move(&self) -> Option<Agent> {
+        self.agent(self.position.x_to_move)
+    }
+
+    /// Change which side each player plays
+    pub const fn swap_agents(&mut self) {
+        core::mem::swap(&mut self.x_agent, &mut self.o_agent);
+    }
+
+    /// The side that lost on time or by resigning, if the game ended that way
+    #[must_use]
+    pub const fn forfeited(&self) -> Option<bool> {
+        self.forfeited
+    }
+
+    /// `Some(true)` if X has won, `Some(false)` if O has
+    #[must_use]
+    pub const fn winner(&self) -> Option<bool> {
+        if let Some(side) = self.forfeited {
+            Some(!side)
+        } else {
+            self.position.winner()
+        }
+    }
+
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.forfeited.is_some() || self.position.is_over()
+    }
+
+    /// Whether a computer player is to move in a game that goes on
+    #[must_use]
+    pub fn is_agent_turn(&self) -> bool {
+        !self.is_over() && self.agent_to_move().is_some()
+    }
+
+    /// Takes the events since the last call, oldest first
+    pub fn events(&mut self) -> std::vec::Drain<'_, GameEvent> {
+        self.events.drain(..)
+    }
+
+    /// Queues the end of the game, or the evaluation if it goes on
+    fn evaluate(&mut self) {
+        self.events.push(if self.is_over() {
+            GameEvent::GameOver {
+                winner: self.winner(),
+            }
+        } else {
+            GameEvent::Evaluation {
+                winner: match evaluate(self.position) {
+                    1 => Some(true),
+                    -1 => Some(false),
+                    _ => None,
+                },
+                distance: distance(self.position),
+            }
+        });
+    }
+
+    /// Plays a move without touching what undo took back
+    fn push(&mut self, index: u8) {
+        let x = self.position.x_to_move;
+        self.position = self.position.play(index);
+        self.history.push(index);
+        self.events.push(GameEvent::MovePlayed { x, square: index });
+        let squares = threats(self.position, x, index);
+        if !self.is_over() && squares != BitBoard::EMPTY {
+            self.events.push(GameEvent::Threat { x, squares });
+        }
+        self.evaluate();
+    }
+
+    /// Takes `index` for the side to move
+    ///
+    /// # Errors
+    ///
+    /// If the game is over or the square isn't empty
+    pub fn play(&mut self, index: u8) -> Result<(), MoveError> {
+        if index >= 9 {
+            return Err(MoveError::OutOfRange);
+        }
+        if self.is_over() {
+            return Err(MoveError::GameOver);
+        }
+        if self.position.board.occupied().get(index) {
+            return Err(MoveError::Occupied);
+        }
+        self.push(index);
+        self.undone.clear();
+        Ok(())
+    }
+
+    /// The square the computer player to move takes, thinking for at most `budget` if given.
+    /// `None` for a human, or once the game is over
+    pub fn agent_choice(&mut self, budget: Option<Duration>) -> Option<u8> {
+        if !self.is_agent_turn() {
+            return None;
+        }
+        let agent = self.agent_to_move()?;
+        Some(match budget {
+            Some(budget) => agent.choose_within(self.position, budget, &mut self.random),
+            None => agent.choose(self.position, &mut self.random),
+        })
+    }
+
+    /// Plays for the computer player to move, returning its square
+    pub fn play_agent(&mut self) -> Option<u8> {
+        let index = self.agent_choice(None)?;
+        self.push(index);
+        self.undone.clear();
+        Some(index)
+    }
+
+    /// A side loses without the board deciding it, such as when its time runs out
+    pub fn forfeit(&mut self, x: bool) {
+        if self.is_over() {
+            return;
+        }
+        self.forfeited = Some(x);
+        self.evaluate();
+    }
+
+    /// Rebuilds the position from the move history
+    fn replay(&mut self) {
+        self.position = Position {
+            board: Board::EMPTY,
+            x_to_move: self.x_moves_first,
+        };
+        self.forfeited = None;
+        for &index in &self.history {
+            self.position = self.position.play(index);
+        }
+    }
+
+    /// Takes back the last move, and the computer's move before it so a human is to move again.
+    /// Moves a computer player made with no human move before them stay, since it would only
+    /// play again at once and lose what could be redone
+    pub fn undo(&mut self) -> bool {
+        let human_to_move = |length: usize| {
+            let x_to_move = self.x_moves_first == length.is_multiple_of(2);
+            self.agent(x_to_move).is_none()
+        };
+        let Some(length) = (0..self.history.len())
+            .rev()
+            .find(|&length| human_to_move(length))
+        else {
+            return false;
+        };
+        let count = self.history.len() - length;
+        self.undone.extend(self.history.drain(length..).rev());
+        self.replay();
+        #[allow(clippy::cast_possible_truncation)]
+        self.events
+            .push(GameEvent::MovesUndone { count: count as u8 });
+        self.evaluate();
+        true
+    }
+
+    /// Plays back the last move taken back, and the computer's reply after it
+    pub fn redo(&mut self) -> bool {
+        let Some(index) = self.undone.pop() else {
+            return false;
+        };
+        self.push(index);
+        while self.is_agent_turn() {
+            let Some(index) = self.undone.pop() else {
+                break;
+            };
+            self.push(index);
+        }
+        true
+    }
+
+    /// Starts again from the empty board, with `x_moves_first` deciding who opens
+    pub fn new_game(&mut self, x_moves_first: bool) {
+        self.x_moves_first = x_moves_first;
+        self.history.clear();
+        self.undone.clear();
+        self.replay();
+        self.events.push(GameEvent::BoardReset { x_moves_first });
+        self.evaluate();
+    }
+
+    /// Puts back a game from its moves, such as one that was saved. The moves must be legal
+    pub fn restore(&mut self, x_moves_first: bool, history: Vec<u8>, undone: Vec<u8>) {
+        self.x_moves_first = x_moves_first;
+        self.history = history;
+        self.undone = undone;
+        self.replay();
+        self.events.push(GameEvent::BoardReset { x_moves_first });
+        let mut x = x_moves_first;
+        for &square in &self.history {
+            self.events.push(GameEvent::MovePlayed { x, square });
+            x = !x;
+        }
+        self.evaluate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn humans() -> GameController {
+        GameController::new(None, None, true, Random::new(0))
+    }
+
+    fn events(controller: &mut GameController) -> Vec<GameEvent> {
+        controller.events().collect()
+    }
+
+    #[test]
+    fn plays_and_reports_moves() {
+        let mut controller = humans();
+        assert_eq!(controller.play(4), Ok(()));
+        assert_eq!(
+            events(&mut controller),
+            [
+                GameEvent::MovePlayed { x: true, square: 4 },
+                GameEvent::Evaluation {
+                    winner: None,
+                    distance: 8
+                },
+            ]
+        );
+        assert!(events(&mut controller).is_empty());
+        assert_eq!(controller.play(4), Err(MoveError::Occupied));
+        assert_eq!(controller.play(9), Err(MoveError::OutOfRange));
+        assert_eq!(controller.history(), [4]);
+        assert!(!controller.position().x_to_move);
+    }
+
+    #[test]
+    fn reports_threats_and_the_end() {
+        let mut controller = humans();
+        for index in [0, 3, 1, 4] {
+            controller.play(index).unwrap();
+        }
+        let threats: Vec<GameEvent> = events(&mut controller)
+            .into_iter()
+            .filter(|event| matches!(event, GameEvent::Threat { .. }))
+            .collect();
+        let (mut x_squares, mut o_squares) = (BitBoard::EMPTY, BitBoard::EMPTY);
+        x_squares.set(2);
+        o_squares.set(5);
+        assert_eq!(
+            threats,
+            [
+                GameEvent::Threat {
+                    x: true,
+                    squares: x_squares
+                },
+                GameEvent::Threat {
+                    x: false,
+                    squares: o_squares
+                },
+            ]
+        );
+
+        controller.play(2).unwrap();
+        assert_eq!(
+            events(&mut controller),
+            [
+                GameEvent::MovePlayed { x: true, square: 2 },
+                GameEvent::GameOver { winner: Some(true) },
+            ]
+        );
+        assert!(controller.is_over());
+        assert_eq!(controller.play(5), Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn undoes_and_redoes() {
+        let mut controller = humans();
+        controller.play(4).unwrap();
+        controller.play(0).unwrap();
+        drop(controller.events());
+
+        assert!(controller.undo());
+        assert_eq!(
+            events(&mut controller),
+            [
+                GameEvent::MovesUndone { count: 1 },
+                GameEvent::Evaluation {
+                    winner: None,
+                    distance: 8
+                },
+            ]
+        );
+        assert_eq!(controller.history(), [4]);
+        assert_eq!(controller.undone(), [0]);
+
+        assert!(controller.redo());
+        assert_eq!(
+            events(&mut controller)[0],
+            GameEvent::MovePlayed {
+                x: false,
+                square: 0
+            }
+        );
+        assert_eq!(controller.history(), [4, 0]);
+        assert!(!controller.redo());
+
+        // A new move throws away what could be redone
+        controller.undo();
+        controller.play(8).unwrap();
+        assert!(controller.undone().is_empty());
+        assert!(!controller.redo());
+    }
+
+    #[test]
+    fn undo_takes_back_the_computer_reply() {
+        let agent = Agent::from_name("perfect", 1);
+        let mut controller = GameController::new(None, agent, true, Random::new(0));
+        controller.play(4).unwrap();
+        let reply = controller.play_agent().unwrap();
+        drop(controller.events());
+
+        assert!(controller.undo());
+        assert_eq!(
+            events(&mut controller)[0],
+            GameEvent::MovesUndone { count: 2 }
+        );
+        assert!(controller.history().is_empty());
+        assert_eq!(controller.undone(), [reply, 4]);
+        assert!(controller.redo());
+        assert_eq!(controller.history(), [4, reply]);
+    }
+
+    #[test]
+    fn undo_keeps_the_computer_opening() {
+        let agent = Agent::from_name("perfect", 1);
+        let mut controller = GameController::new(agent, None, true, Random::new(0));
+        let opening = controller.play_agent().unwrap();
+        assert!(!controller.undo());
+        assert_eq!(controller.history(), [opening]);
+
+        let reply = (0..9).find(|index| *index != opening).unwrap();
+        controller.play(reply).unwrap();
+        let answer = controller.play_agent().unwrap();
+        assert!(controller.undo());
+        assert_eq!(controller.history(), [opening]);
+        assert!(!controller.is_agent_turn());
+        assert!(controller.redo());
+        assert_eq!(controller.history(), [opening, reply, answer]);
+    }
+
+    #[test]
+    fn resets_and_restores() {
+        let mut controller = humans();
+        controller.play(4).unwrap();
+        controller.new_game(false);
+        let events = events(&mut controller);
+        assert_eq!(
+            events[events.len() - 2],
+            GameEvent::BoardReset {
+                x_moves_first: false
+            }
+        );
+        assert!(controller.history().is_empty());
+        assert!(!controller.position().x_to_move);
+
+        controller.restore(true, vec![0, 4], vec![8]);
+        assert_eq!(
+            self::events(&mut controller)[..3],
+            [
+                GameEvent::BoardReset {
+                    x_moves_first: true
+                },
+                GameEvent::MovePlayed { x: true, square: 0 },
+                GameEvent::MovePlayed {
+                    x: false,
+                    square: 4
+                },
+            ]
+        );
+        assert!(controller.redo());
+        assert_eq!(controller.history(), [0, 4, 8]);
+    }
+
+    #[test]
+    fn forfeits() {
+        let mut controller = humans();
+        controller.play(4).unwrap();
+        controller.forfeit(false);
+        assert!(controller.is_over());
+        assert_eq!(controller.winner(), Some(true));
+        assert_eq!(controller.forfeited(), Some(false));
+        assert_eq!(controller.play(0), Err(MoveError::GameOver));
+    }
+}
@@ -0,0 +1,902 @@
+use crate::board::bit_board::BitBoard;
+use crate::board::Board;
+use crate::position::Position;
+
+/// [`evaluate_for_x`] for either side moving first.
+/// A game O started is the same as one X started with the marks swapped
+///
+/// # Panics
+///
+/// If the position can't come up in a game, such as one where a side has two more marks
+#[must_use]
+pub const fn evaluate(position: Position) -> i8 {
+    match lookup(position) {
+        Some(evaluation) => evaluation,
+        None => panic!("the position can't come up in a game"),
+    }
+}
+
+/// [`evaluate`], or `None` for a position the compiled table doesn't have
+#[must_use]
+pub const fn lookup(position: Position) -> Option<i8> {
+    let (x, o) = (position.board.x.count(), position.board.o.count());
+    let x_moved_first = if position.x_to_move {
+        x == o
+    } else {
+        x == o + 1
+    };
+    if x_moved_first {
+        lookup_for_x(position.board)
+    } else {
+        match lookup_for_x(Board {
+            x: position.board.o,
+            o: position.board.x,
+        }) {
+            Some(evaluation) => Some(-evaluation),
+            None => None,
+        }
+    }
+}
+
+/// Squares that keep the best evaluation for the side to move
+#[must_use]
+pub fn best_moves(position: Position) -> BitBoard {
+    let (mut best_moves, mut best_score) = (BitBoard::EMPTY, -1);
+
+    let mut moves = position.moves();
+    while moves != BitBoard::EMPTY {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = moves.pop() as u8;
+        let evaluation = evaluate(position.play(index));
+        let evaluation = if position.x_to_move {
+            evaluation
+        } else {
+            -evaluation
+        };
+        if evaluation >= best_score {
+            if evaluation != best_score {
+                best_score = evaluation;
+                best_moves = BitBoard::EMPTY;
+            }
+            best_moves.set(index);
+        }
+    }
+
+    best_moves
+}
+
+/// What taking a square leads to for the side that takes it
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Outcome {
+    /// 1 if it can then force a win, -1 if the other side can, 0 for a draw
+    pub result: i8,
+    /// Plies until the game ends with perfect play, counting the move itself.
+    /// The winner takes the fastest win and the loser the slowest loss
+    pub distance: u8,
+}
+
+/// [`best_moves`] narrowed down by distance: the fastest win, or the slowest loss
+#[must_use]
+pub fn perfect_moves(position: Position) -> BitBoard {
+    // Higher is better: the result first, then fewer plies to a win or more to a loss
+    let rank = |outcome: Outcome| {
+        (
+            outcome.result,
+            i16::from(outcome.distance) * -i16::from(outcome.result),
+        )
+    };
+    let outcomes = outcomes(position);
+    let best = outcomes
+        .iter()
+        .flatten()
+        .map(|outcome| rank(*outcome))
+        .max();
+
+    let mut moves = BitBoard::EMPTY;
+    for (index, outcome) in (0..9).zip(outcomes) {
+        if outcome.map(rank) == best {
+            moves.set(index);
+        }
+    }
+    moves
+}
+
+/// Perfect-play result for the side to move, 1, 0 or -1
+const fn evaluate_for_mover(position: Position) -> i8 {
+    if position.x_to_move {
+        evaluate(position)
+    } else {
+        -evaluate(position)
+    }
+}
+
+/// Plies until the game ends with perfect play from `position`
+#[must_use]
+pub fn distance(position: Position) -> u8 {
+    if position.is_over() {
+        return 0;
+    }
+    let result = evaluate_for_mover(position);
+    #[allow(clippy::cast_possible_truncation)]
+    let empty = (9 - position.board.occupied().count()) as u8;
+    // Nobody wins a drawn game, so it goes on until the board is full
+    if result == 0 {
+        return empty;
+    }
+
+    // The fastest win, or the slowest loss
+    let mut best = if result == 1 { u8::MAX } else { 0 };
+    let mut moves = position.moves();
+    while moves != BitBoard::EMPTY {
+        #[allow(clippy::cast_possible_truncation)]
+        let child = position.play(moves.pop() as u8);
+        if result == 1 && evaluate_for_mover(child) == -1 {
+            best = best.min(1 + distance(child));
+        } else if result == -1 {
+            best = best.max(1 + distance(child));
+        }
+    }
+    best
+}
+
+/// [`Outcome`] of taking each empty square, indexed by square
+#[must_use]
+pub fn outcomes(position: Position) -> [Option<Outcome>; 9] {
+    let mut outcomes = [None; 9];
+    let mut moves = position.moves();
+    while moves != BitBoard::EMPTY {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = moves.pop() as u8;
+        let child = position.play(index);
+        outcomes[index as usize] = Some(Outcome {
+            result: -evaluate_for_mover(child),
+            distance: 1 + distance(child),
+        });
+    }
+    outcomes
+}
+
+/// Perfect-play result from X's point of view: 1 if X can force a win, -1 if O can, 0 if it's a draw.
+/// Covers every board reachable with X moving first
+///
+/// # Panics
+///
+/// If the board can't come up in a game X started
+#[must_use]
+pub const fn evaluate_for_x(board: Board) -> i8 {
+    match lookup_for_x(board) {
+        Some(evaluation) => evaluation,
+        None => panic!("the board can't come up in a game X started"),
+    }
+}
+
+/// [`evaluate_for_x`], or `None` for a board the table doesn't have.
+/// The match is generated by `lookup_generator`
+#[allow(clippy::too_many_lines)]
+#[must_use]
+pub const fn lookup_for_x(board: Board) -> Option<i8> {
+    let position = (board.x.as_u32()) | (board.o.as_u32()) << 9;
+    match position {
+        0x0 | 0x1 | 0x2 | 0x4 | 0x8 | 0x10 | 0x20 | 0x40 | 0x80 | 0x100 | 0x202 | 0x208 | 0x20a
+        | 0x20c | 0x210 | 0x212 | 0x214 | 0x218 | 0x230 | 0x242 | 0x250 | 0x260 | 0x284 | 0x290
+        | 0x302 | 0x308 | 0x310 | 0x405 | 0x40c | 0x421 | 0x444 | 0x460 | 0x480 | 0x481 | 0x484
+        | 0x488 | 0x490 | 0x4a0 | 0x4c0 | 0x501 | 0x508 | 0x580 | 0x690 | 0x694 | 0x802 | 0x810
+        | 0x811 | 0x812 | 0x818 | 0x820 | 0x821 | 0x822 | 0x830 | 0x842 | 0x850 | 0x860 | 0x881
+        | 0x890 | 0x902 | 0x908 | 0x910 | 0xa50 | 0xa52 | 0xa60 | 0xa62 | 0xb08 | 0xb0a | 0xb10
+        | 0xb12 | 0xb42 | 0xc90 | 0xc91 | 0x1020 | 0x1021 | 0x1022 | 0x1024 | 0x1030 | 0x1041
+        | 0x1042 | 0x1044 | 0x1060 | 0x1081 | 0x1084 | 0x10a0 | 0x1101 | 0x1102 | 0x1120
+        | 0x1230 | 0x1270 | 0x1425 | 0x1431 | 0x1461 | 0x1470 | 0x1485 | 0x1491 | 0x1494
+        | 0x14a1 | 0x14a4 | 0x14c1 | 0x14e0 | 0x1821 | 0x1822 | 0x1823 | 0x1830 | 0x1831
+        | 0x1832 | 0x1851 | 0x1852 | 0x1860 | 0x1861 | 0x1862 | 0x1870 | 0x18a0 | 0x18a1
+        | 0x18a2 | 0x18b0 | 0x18c1 | 0x18e0 | 0x1920 | 0x1921 | 0x1942 | 0x1960 | 0x1a70
+        | 0x1a72 | 0x1b22 | 0x1b52 | 0x1b62 | 0x1c70 | 0x1c71 | 0x1cb0 | 0x1cb1 | 0x1cd1
+        | 0x1ce1 | 0x2001 | 0x2002 | 0x2003 | 0x2004 | 0x2005 | 0x2006 | 0x2008 | 0x2009
+        | 0x200a | 0x200c | 0x2020 | 0x2021 | 0x2022 | 0x2024 | 0x2040 | 0x2041 | 0x2042
+        | 0x2044 | 0x2048 | 0x2060 | 0x2080 | 0x2081 | 0x2084 | 0x2088 | 0x20a0 | 0x20c0
+        | 0x2100 | 0x2101 | 0x2102 | 0x2104 | 0x2108 | 0x2120 | 0x2140 | 0x2180 | 0x2206
+        | 0x220a | 0x220c | 0x2222 | 0x2242 | 0x2248 | 0x2288 | 0x2302 | 0x2306 | 0x2308
+        | 0x230a | 0x230c | 0x2322 | 0x2342 | 0x2348 | 0x2388 | 0x2405 | 0x240c | 0x2421
+        | 0x2444 | 0x2460 | 0x2485 | 0x2489 | 0x248c | 0x24a1 | 0x24a4 | 0x24c4 | 0x24e0
+        | 0x2501 | 0x2508 | 0x2581 | 0x2588 | 0x2803 | 0x280a | 0x2821 | 0x2822 | 0x2842
+        | 0x2843 | 0x284a | 0x2860 | 0x2861 | 0x2862 | 0x28a0 | 0x28e0 | 0x2902 | 0x2920
+        | 0x2942 | 0x2960 | 0x2a4a | 0x2a62 | 0x2b0a | 0x2b22 | 0x2b4a | 0x2b62 | 0x3023
+        | 0x3041 | 0x3042 | 0x3044 | 0x3061 | 0x3062 | 0x3064 | 0x3081 | 0x3084 | 0x30a1
+        | 0x30a4 | 0x30e0 | 0x3101 | 0x3102 | 0x3121 | 0x3122 | 0x3461 | 0x3485 | 0x34a5
+        | 0x34e1 | 0x34e4 | 0x3823 | 0x3843 | 0x3861 | 0x3862 | 0x3863 | 0x38a1 | 0x38a2
+        | 0x38e1 | 0x38e2 | 0x3921 | 0x3922 | 0x3961 | 0x3962 | 0x4008 | 0x4009 | 0x400a
+        | 0x400c | 0x4018 | 0x4042 | 0x4044 | 0x4048 | 0x4081 | 0x4084 | 0x4088 | 0x4101
+        | 0x4102 | 0x4104 | 0x4108 | 0x420a | 0x420c | 0x420e | 0x4218 | 0x421a | 0x421c
+        | 0x4248 | 0x424c | 0x4288 | 0x428a | 0x428c | 0x4298 | 0x4308 | 0x430a | 0x430c
+        | 0x4312 | 0x4314 | 0x4318 | 0x4342 | 0x4348 | 0x4384 | 0x4388 | 0x440d | 0x441c
+        | 0x4485 | 0x4489 | 0x448c | 0x4491 | 0x4494 | 0x450c | 0x4518 | 0x4584 | 0x4588
+        | 0x4698 | 0x469c | 0x4718 | 0x471c | 0x478c | 0x4794 | 0x4818 | 0x4918 | 0x4a4a
+        | 0x4b18 | 0x4b1a | 0x4b4a | 0x4b52 | 0x600e | 0x6042 | 0x6044 | 0x604a | 0x604c
+        | 0x6081 | 0x6084 | 0x6089 | 0x608c | 0x6101 | 0x6102 | 0x6104 | 0x6109 | 0x610a
+        | 0x610c | 0x6188 | 0x620e | 0x624a | 0x624c | 0x628a | 0x628c | 0x6306 | 0x630a
+        | 0x630c | 0x630e | 0x634a | 0x634c | 0x638a | 0x638c | 0x6485 | 0x648d | 0x650c
+        | 0x6589 | 0x658c | 0x8008 | 0x800c | 0x8010 | 0x8011 | 0x8012 | 0x8014 | 0x8018
+        | 0x8021 | 0x8030 | 0x8080 | 0x8081 | 0x8084 | 0x8088 | 0x8090 | 0x8102 | 0x8108
+        | 0x8110 | 0x8214 | 0x821c | 0x8284 | 0x828c | 0x8302 | 0x830a | 0x830c | 0x8310
+        | 0x8318 | 0x8415 | 0x841c | 0x8425 | 0x8481 | 0x8484 | 0x8485 | 0x8488 | 0x8489
+        | 0x848c | 0x8490 | 0x8491 | 0x8494 | 0x8498 | 0x84a0 | 0x84a1 | 0x84a4 | 0x84a8
+        | 0x84b0 | 0x850c | 0x8580 | 0x8581 | 0x8584 | 0x8694 | 0x869c | 0x871c | 0x8788
+        | 0x878c | 0x9030 | 0x9031 | 0x9435 | 0x9494 | 0x9495 | 0x94a5 | 0x94b0 | 0x94b1
+        | 0xa009 | 0xa00a | 0xa00c | 0xa00d | 0xa00e | 0xa081 | 0xa084 | 0xa085 | 0xa088
+        | 0xa08c | 0xa0a0 | 0xa0a4 | 0xa108 | 0xa10c | 0xa180 | 0xa184 | 0xa20e | 0xa28c
+        | 0xa30a | 0xa30e | 0xa388 | 0xa38c | 0xa40d | 0xa485 | 0xa489 | 0xa48c | 0xa48d
+        | 0xa4a1 | 0xa4a5 | 0xa4a8 | 0xa4ac | 0xa581 | 0xa585 | 0xa588 | 0xa58c | 0xc009
+        | 0xc00a | 0xc00c | 0xc00d | 0xc00e | 0xc018 | 0xc01a | 0xc01c | 0xc085 | 0xc088
+        | 0xc08a | 0xc08c | 0xc094 | 0xc098 | 0xc106 | 0xc108 | 0xc109 | 0xc10a | 0xc10c
+        | 0xc114 | 0xc118 | 0xc188 | 0xc20e | 0xc21c | 0xc21e | 0xc28c | 0xc28e | 0xc29c
+        | 0xc306 | 0xc30a | 0xc30c | 0xc30e | 0xc314 | 0xc318 | 0xc31a | 0xc31c | 0xc384
+        | 0xc388 | 0xc38a | 0xc38c | 0xc398 | 0xc40d | 0xc41c | 0xc41d | 0xc485 | 0xc489
+        | 0xc48c | 0xc48d | 0xc494 | 0xc495 | 0xc498 | 0xc499 | 0xc49c | 0xc50c | 0xc50d
+        | 0xc51c | 0xc584 | 0xc585 | 0xc588 | 0xc589 | 0xc58c | 0xc594 | 0xc598 | 0xc69c
+        | 0xc71c | 0xc78c | 0xc794 | 0xc798 | 0xc79c | 0xe089 | 0xe08a | 0xe08c | 0xe08d
+        | 0xe08e | 0xe109 | 0xe10a | 0xe10c | 0xe10d | 0xe10e | 0xe184 | 0xe188 | 0xe18c
+        | 0xe28e | 0xe30e | 0xe386 | 0xe38a | 0xe38c | 0xe38e | 0xe48d | 0xe50d | 0xe585
+        | 0xe589 | 0xe58c | 0xe58d | 0x10002 | 0x10003 | 0x10006 | 0x1000a | 0x1000c | 0x10012
+        | 0x10021 | 0x10022 | 0x10042 | 0x10044 | 0x10060 | 0x10101 | 0x10102 | 0x10108
+        | 0x10140 | 0x10206 | 0x1020a | 0x10212 | 0x1021a | 0x10222 | 0x1022a | 0x10232
+        | 0x10242 | 0x10246 | 0x1024a | 0x10252 | 0x10262 | 0x10302 | 0x10306 | 0x1030a
+        | 0x1030c | 0x10312 | 0x10318 | 0x10322 | 0x10342 | 0x10350 | 0x10360 | 0x10803
+        | 0x1080a | 0x10812 | 0x1081a | 0x10822 | 0x1082a | 0x10832 | 0x10842 | 0x10843
+        | 0x1084a | 0x10852 | 0x10861 | 0x10862 | 0x10870 | 0x10902 | 0x10903 | 0x1090a
+        | 0x10912 | 0x10922 | 0x10942 | 0x10948 | 0x10950 | 0x10a4a | 0x10a52 | 0x10a5a
+        | 0x10a62 | 0x10a6a | 0x10a72 | 0x10b0a | 0x10b12 | 0x10b1a | 0x10b22 | 0x10b2a
+        | 0x10b32 | 0x10b42 | 0x10b48 | 0x10b4a | 0x10b50 | 0x10b52 | 0x10b60 | 0x10b62
+        | 0x11023 | 0x11031 | 0x11043 | 0x11052 | 0x11061 | 0x11062 | 0x11070 | 0x11112
+        | 0x11122 | 0x11142 | 0x11160 | 0x11232 | 0x11272 | 0x11312 | 0x11352 | 0x11362
+        | 0x11370 | 0x11823 | 0x11832 | 0x11833 | 0x11843 | 0x11852 | 0x11853 | 0x11861
+        | 0x11862 | 0x11863 | 0x11870 | 0x11871 | 0x11872 | 0x11922 | 0x11923 | 0x11932
+        | 0x11942 | 0x11943 | 0x11952 | 0x11960 | 0x11961 | 0x11962 | 0x11970 | 0x11a72
+        | 0x11b32 | 0x11b52 | 0x11b62 | 0x11b70 | 0x11b72 | 0x1200c | 0x1200e | 0x12021
+        | 0x12023 | 0x12044 | 0x12046 | 0x1204a | 0x12060 | 0x12062 | 0x12101 | 0x12103
+        | 0x12108 | 0x1210a | 0x12122 | 0x12140 | 0x12142 | 0x1220e | 0x1222a | 0x12246
+        | 0x1224a | 0x12262 | 0x1230a | 0x1230e | 0x1232a | 0x12342 | 0x12346 | 0x12348
+        | 0x1234a | 0x12362 | 0x12823 | 0x1282a | 0x12862 | 0x12863 | 0x1286a | 0x12903
+        | 0x1290a | 0x12922 | 0x12942 | 0x12943 | 0x1294a | 0x12960 | 0x12962 | 0x12a6a
+        | 0x12b2a | 0x12b4a | 0x12b62 | 0x12b68 | 0x12b6a | 0x13061 | 0x13063 | 0x13123
+        | 0x13142 | 0x13162 | 0x13863 | 0x13923 | 0x13943 | 0x13961 | 0x13962 | 0x13963
+        | 0x1400e | 0x1401c | 0x1404a | 0x14052 | 0x14106 | 0x1410a | 0x1410c | 0x14112
+        | 0x14118 | 0x14142 | 0x14148 | 0x1420e | 0x1421a | 0x1421e | 0x1424a | 0x1424e
+        | 0x1425a | 0x14306 | 0x1430a | 0x1430c | 0x1430e | 0x14312 | 0x14316 | 0x14318
+        | 0x1431a | 0x1431c | 0x14342 | 0x14346 | 0x14348 | 0x1434a | 0x1434c | 0x14352
+        | 0x14358 | 0x1481a | 0x14852 | 0x1491a | 0x1494a | 0x14952 | 0x14958 | 0x14a5a
+        | 0x14b1a | 0x14b4a | 0x14b52 | 0x14b58 | 0x14b5a | 0x1604e | 0x1610c | 0x1610e
+        | 0x16142 | 0x1614a | 0x1624e | 0x1630e | 0x16346 | 0x1634a | 0x1634c | 0x1634e
+        | 0x18012 | 0x18112 | 0x1820e | 0x1830e | 0x18312 | 0x1831a | 0x1831c | 0x1c01a
+        | 0x1c01c | 0x1c10e | 0x1c116 | 0x1c11a | 0x1c11c | 0x1c21e | 0x1c30e | 0x1c316
+        | 0x1c31a | 0x1c31c | 0x1c31e | 0x2000c | 0x20010 | 0x20011 | 0x20012 | 0x20014
+        | 0x20018 | 0x20020 | 0x20021 | 0x20030 | 0x20042 | 0x20050 | 0x20060 | 0x20080
+        | 0x20081 | 0x20084 | 0x20090 | 0x200a0 | 0x2040d | 0x20415 | 0x20431 | 0x20461
+        | 0x20481 | 0x20484 | 0x20485 | 0x20488 | 0x20489 | 0x2048c | 0x20490 | 0x20491
+        | 0x20494 | 0x20498 | 0x204a0 | 0x204a1 | 0x204a4 | 0x204a8 | 0x204b0 | 0x204c0
+        | 0x204c1 | 0x204c4 | 0x20811 | 0x20831 | 0x20842 | 0x20850 | 0x20861 | 0x20862
+        | 0x20870 | 0x20881 | 0x208a1 | 0x20c71 | 0x20c91 | 0x20cb1 | 0x20ce0 | 0x20ce1
+        | 0x21021 | 0x21022 | 0x21023 | 0x21024 | 0x21025 | 0x21030 | 0x21031 | 0x21032
+        | 0x21043 | 0x21051 | 0x21060 | 0x21061 | 0x21062 | 0x21064 | 0x21070 | 0x21085
+        | 0x21091 | 0x210a0 | 0x210a1 | 0x210a2 | 0x210b0 | 0x210e0 | 0x21425 | 0x21431
+        | 0x21435 | 0x21461 | 0x21465 | 0x21471 | 0x21485 | 0x21491 | 0x21495 | 0x214a1
+        | 0x214a4 | 0x214a5 | 0x214b0 | 0x214b1 | 0x214b4 | 0x214c1 | 0x214c5 | 0x214d1
+        | 0x214e0 | 0x214e1 | 0x214e4 | 0x214f0 | 0x21823 | 0x21831 | 0x21833 | 0x21843
+        | 0x21851 | 0x21861 | 0x21862 | 0x21863 | 0x21870 | 0x21871 | 0x21872 | 0x218a1
+        | 0x218a3 | 0x218b1 | 0x218c1 | 0x218e0 | 0x218e1 | 0x218e2 | 0x218f0 | 0x21c71
+        | 0x21cb1 | 0x21cd1 | 0x21ce1 | 0x21cf0 | 0x21cf1 | 0x22021 | 0x22022 | 0x22023
+        | 0x22024 | 0x22025 | 0x22060 | 0x22061 | 0x22081 | 0x22084 | 0x22085 | 0x22088
+        | 0x22089 | 0x220a0 | 0x220a1 | 0x220c0 | 0x220c1 | 0x22425 | 0x22485 | 0x2248c
+        | 0x2248d | 0x224a1 | 0x224a4 | 0x224a5 | 0x224a8 | 0x224a9 | 0x224c4 | 0x224c5
+        | 0x224e0 | 0x224e1 | 0x22823 | 0x22862 | 0x22863 | 0x228a1 | 0x228e0 | 0x228e1
+        | 0x23061 | 0x23062 | 0x23063 | 0x23064 | 0x23065 | 0x230a1 | 0x230a2 | 0x230a3
+        | 0x230a4 | 0x230a5 | 0x230c1 | 0x230e0 | 0x230e1 | 0x23465 | 0x234a5 | 0x234c5
+        | 0x234e1 | 0x234e4 | 0x234e5 | 0x23863 | 0x238a3 | 0x238c3 | 0x238e1 | 0x238e2
+        | 0x238e3 | 0x24018 | 0x2401c | 0x2441d | 0x2448d | 0x24491 | 0x24495 | 0x24498
+        | 0x2449c | 0x2800c | 0x28011 | 0x28014 | 0x28021 | 0x28085 | 0x2808c | 0x28091
+        | 0x28094 | 0x280a1 | 0x2840d | 0x28415 | 0x28425 | 0x28485 | 0x28489 | 0x2848c
+        | 0x2848d | 0x28491 | 0x28494 | 0x28495 | 0x28499 | 0x2849c | 0x284a1 | 0x284a4
+        | 0x284a5 | 0x284a9 | 0x284ac | 0x284b1 | 0x284b4 | 0x29031 | 0x29095 | 0x290a4
+        | 0x290a5 | 0x290b1 | 0x29435 | 0x29495 | 0x294a5 | 0x294b1 | 0x294b4 | 0x294b5
+        | 0x2a089 | 0x2a08c | 0x2a08d | 0x2a0a1 | 0x2a0a4 | 0x2a0a5 | 0x2a42d | 0x2a48d
+        | 0x2a4a5 | 0x2a4a9 | 0x2a4ac | 0x2a4ad | 0x2c01c | 0x2c089 | 0x2c08d | 0x2c095
+        | 0x2c09c | 0x2c41d | 0x2c48d | 0x2c495 | 0x2c499 | 0x2c49c | 0x2c49d | 0x30012
+        | 0x30052 | 0x30823 | 0x30852 | 0x30863 | 0x30871 | 0x30872 | 0x31031 | 0x31032
+        | 0x31053 | 0x31063 | 0x31071 | 0x31072 | 0x31833 | 0x31853 | 0x31863 | 0x31871
+        | 0x31872 | 0x31873 => Some(0),
+        0x204 | 0x220 | 0x224 | 0x240 | 0x244 | 0x280 | 0x2c0 | 0x300 | 0x304 | 0x340 | 0x401
+        | 0x404 | 0x408 | 0x409 | 0x410 | 0x411 | 0x414 | 0x418 | 0x420 | 0x424 | 0x430 | 0x440
+        | 0x441 | 0x450 | 0x500 | 0x504 | 0x510 | 0x540 | 0x60c | 0x614 | 0x618 | 0x61c | 0x624
+        | 0x628 | 0x62c | 0x630 | 0x634 | 0x638 | 0x644 | 0x650 | 0x654 | 0x660 | 0x664 | 0x684
+        | 0x688 | 0x68c | 0x6a0 | 0x6a4 | 0x6c0 | 0x6c4 | 0x704 | 0x708 | 0x70c | 0x710 | 0x714
+        | 0x720 | 0x724 | 0x740 | 0x744 | 0x780 | 0x784 | 0x7c0 | 0x801 | 0x808 | 0x809 | 0x840
+        | 0x841 | 0x880 | 0x900 | 0x901 | 0x940 | 0x980 | 0xa0a | 0xa12 | 0xa18 | 0xa1a | 0xa22
+        | 0xa28 | 0xa30 | 0xa32 | 0xa38 | 0xa42 | 0xa82 | 0xa90 | 0xa92 | 0xac0 | 0xac2 | 0xb02
+        | 0xb40 | 0xb80 | 0xb82 | 0xbc0 | 0xc09 | 0xc11 | 0xc18 | 0xc19 | 0xc21 | 0xc28 | 0xc29
+        | 0xc30 | 0xc31 | 0xc38 | 0xc41 | 0xc48 | 0xc49 | 0xc50 | 0xc51 | 0xc60 | 0xc61 | 0xc81
+        | 0xc88 | 0xc89 | 0xca0 | 0xca1 | 0xcc0 | 0xcc1 | 0xd01 | 0xd08 | 0xd09 | 0xd10 | 0xd11
+        | 0xd40 | 0xd41 | 0xd80 | 0xd81 | 0xdc0 | 0x1001 | 0x1002 | 0x1003 | 0x1004 | 0x1005
+        | 0x1010 | 0x1011 | 0x1012 | 0x1014 | 0x1040 | 0x1050 | 0x1080 | 0x1090 | 0x10c0
+        | 0x1100 | 0x1104 | 0x1110 | 0x1140 | 0x1212 | 0x1214 | 0x1222 | 0x1224 | 0x1242
+        | 0x1244 | 0x1250 | 0x1252 | 0x1254 | 0x1260 | 0x1262 | 0x1264 | 0x1282 | 0x1284
+        | 0x1290 | 0x1292 | 0x12a0 | 0x12c0 | 0x12c2 | 0x12c4 | 0x12d0 | 0x12e0 | 0x1302
+        | 0x1304 | 0x1310 | 0x1320 | 0x1324 | 0x1340 | 0x1342 | 0x1344 | 0x1350 | 0x1360
+        | 0x1380 | 0x13c0 | 0x1405 | 0x1411 | 0x1414 | 0x1415 | 0x1421 | 0x1424 | 0x1430
+        | 0x1434 | 0x1441 | 0x1444 | 0x1450 | 0x1451 | 0x1454 | 0x1460 | 0x1464 | 0x1481
+        | 0x1484 | 0x1490 | 0x14a0 | 0x14c0 | 0x14c4 | 0x14d0 | 0x1501 | 0x1504 | 0x1505
+        | 0x1510 | 0x1511 | 0x1514 | 0x1520 | 0x1521 | 0x1524 | 0x1530 | 0x1540 | 0x1541
+        | 0x1544 | 0x1550 | 0x1560 | 0x1580 | 0x1581 | 0x1584 | 0x1590 | 0x15a0 | 0x15c0
+        | 0x1634 | 0x1664 | 0x1670 | 0x1674 | 0x1694 | 0x16a4 | 0x16c4 | 0x16d0 | 0x16d4
+        | 0x16e0 | 0x16e4 | 0x1714 | 0x1730 | 0x1734 | 0x1744 | 0x1750 | 0x1754 | 0x1760
+        | 0x1764 | 0x1784 | 0x1790 | 0x17a0 | 0x17a4 | 0x17c4 | 0x17d0 | 0x17e0 | 0x1803
+        | 0x1811 | 0x1812 | 0x1813 | 0x1841 | 0x1842 | 0x1850 | 0x1881 | 0x1882 | 0x1890
+        | 0x1891 | 0x1892 | 0x18c0 | 0x18c2 | 0x18d0 | 0x1901 | 0x1902 | 0x1910 | 0x1911
+        | 0x1912 | 0x1940 | 0x1941 | 0x1950 | 0x1980 | 0x1981 | 0x1982 | 0x1990 | 0x19c0
+        | 0x1a32 | 0x1a52 | 0x1a62 | 0x1aa2 | 0x1ab0 | 0x1ab2 | 0x1ac2 | 0x1ad0 | 0x1ad2
+        | 0x1ae0 | 0x1ae2 | 0x1b12 | 0x1b42 | 0x1b50 | 0x1b60 | 0x1b82 | 0x1b90 | 0x1b92
+        | 0x1ba0 | 0x1bc2 | 0x1bd0 | 0x1be0 | 0x1c31 | 0x1c51 | 0x1c61 | 0x1c91 | 0x1ca1
+        | 0x1cc1 | 0x1cd0 | 0x1ce0 | 0x1d21 | 0x1d30 | 0x1d31 | 0x1d41 | 0x1d50 | 0x1d51
+        | 0x1d60 | 0x1d61 | 0x1d81 | 0x1d90 | 0x1d91 | 0x1da0 | 0x1da1 | 0x1dc1 | 0x1dd0
+        | 0x1de0 | 0x2224 | 0x2244 | 0x2260 | 0x2284 | 0x22a0 | 0x22c0 | 0x2304 | 0x2320
+        | 0x2324 | 0x2340 | 0x2344 | 0x2360 | 0x2380 | 0x2384 | 0x23a0 | 0x23c0 | 0x2409
+        | 0x2424 | 0x2441 | 0x2448 | 0x2449 | 0x2481 | 0x2484 | 0x2488 | 0x24a0 | 0x24c0
+        | 0x24c1 | 0x24c8 | 0x2504 | 0x2520 | 0x2524 | 0x2540 | 0x2580 | 0x2584 | 0x25a0
+        | 0x25c0 | 0x262c | 0x2664 | 0x268c | 0x26a4 | 0x26c4 | 0x26c8 | 0x26e0 | 0x270c
+        | 0x2728 | 0x272c | 0x2744 | 0x2748 | 0x2760 | 0x2764 | 0x2784 | 0x2788 | 0x278c
+        | 0x27a0 | 0x27a4 | 0x27c4 | 0x27c8 | 0x27e0 | 0x2809 | 0x2841 | 0x2848 | 0x2849
+        | 0x2881 | 0x2888 | 0x28c0 | 0x28c1 | 0x28c8 | 0x2901 | 0x2908 | 0x2940 | 0x2941
+        | 0x2948 | 0x2980 | 0x29c0 | 0x2ac2 | 0x2ac8 | 0x2ae0 | 0x2b42 | 0x2b48 | 0x2b60
+        | 0x2b82 | 0x2b88 | 0x2ba0 | 0x2bc2 | 0x2bc8 | 0x2be0 | 0x2c29 | 0x2c61 | 0x2c68
+        | 0x2c69 | 0x2c89 | 0x2ca1 | 0x2cc1 | 0x2cc8 | 0x2cc9 | 0x2ce0 | 0x2ce1 | 0x2d09
+        | 0x2d41 | 0x2d48 | 0x2d49 | 0x2d60 | 0x2d81 | 0x2d88 | 0x2da0 | 0x2dc1 | 0x2dc8
+        | 0x2de0 | 0x3003 | 0x3005 | 0x3006 | 0x3007 | 0x3021 | 0x3022 | 0x3024 | 0x3025
+        | 0x3026 | 0x3060 | 0x30a0 | 0x30c0 | 0x3104 | 0x3120 | 0x3124 | 0x3140 | 0x3160
+        | 0x3180 | 0x31a0 | 0x31c0 | 0x3226 | 0x3262 | 0x3264 | 0x32a4 | 0x32c2 | 0x32c4
+        | 0x32e0 | 0x3306 | 0x3322 | 0x3326 | 0x3342 | 0x3344 | 0x3360 | 0x3362 | 0x3364
+        | 0x3382 | 0x3384 | 0x33a0 | 0x33a4 | 0x33c2 | 0x33c4 | 0x33e0 | 0x3425 | 0x3464
+        | 0x34a1 | 0x34a4 | 0x34c1 | 0x34c4 | 0x34e0 | 0x3505 | 0x3521 | 0x3525 | 0x3541
+        | 0x3544 | 0x3560 | 0x3564 | 0x3581 | 0x3584 | 0x35a0 | 0x35a1 | 0x35a4 | 0x35c1
+        | 0x35c4 | 0x35e0 | 0x36e4 | 0x37e4 | 0x38c1 | 0x38c2 | 0x38e0 | 0x3941 | 0x3942
+        | 0x3960 | 0x3981 | 0x3982 | 0x39a0 | 0x39c1 | 0x39c2 | 0x39e0 | 0x3ae2 | 0x3b62
+        | 0x3ba2 | 0x3be2 | 0x3ce1 | 0x3d61 | 0x3da1 | 0x3de1 | 0x4001 | 0x4002 | 0x4004
+        | 0x4005 | 0x4006 | 0x4010 | 0x4011 | 0x4012 | 0x4014 | 0x4040 | 0x4041 | 0x4050
+        | 0x4080 | 0x4090 | 0x4100 | 0x4110 | 0x4140 | 0x4180 | 0x4206 | 0x4212 | 0x4214
+        | 0x4216 | 0x4242 | 0x4244 | 0x4250 | 0x4252 | 0x4254 | 0x4282 | 0x4284 | 0x4290
+        | 0x4292 | 0x4294 | 0x42c0 | 0x42c2 | 0x42c4 | 0x42d0 | 0x4302 | 0x4304 | 0x4310
+        | 0x4340 | 0x4344 | 0x4350 | 0x4380 | 0x4382 | 0x4390 | 0x43c0 | 0x4405 | 0x4409
+        | 0x440c | 0x4411 | 0x4414 | 0x4415 | 0x4418 | 0x4419 | 0x4441 | 0x4444 | 0x4445
+        | 0x4448 | 0x4449 | 0x444c | 0x4450 | 0x4451 | 0x4454 | 0x4458 | 0x4481 | 0x4484
+        | 0x4488 | 0x4490 | 0x44c0 | 0x44c1 | 0x44c4 | 0x44c8 | 0x44d0 | 0x4501 | 0x4504
+        | 0x4508 | 0x4509 | 0x4510 | 0x4511 | 0x4514 | 0x4540 | 0x4541 | 0x4544 | 0x4548
+        | 0x4550 | 0x4580 | 0x4581 | 0x4590 | 0x45c0 | 0x461c | 0x464c | 0x4658 | 0x465c
+        | 0x468c | 0x4694 | 0x46c4 | 0x46c8 | 0x46cc | 0x46d0 | 0x46d4 | 0x470c | 0x4714
+        | 0x4744 | 0x4748 | 0x474c | 0x4750 | 0x4754 | 0x4784 | 0x4788 | 0x4790 | 0x47c4
+        | 0x47c8 | 0x47d0 | 0x4809 | 0x480a | 0x4811 | 0x4812 | 0x4841 | 0x4842 | 0x4848
+        | 0x4849 | 0x4850 | 0x4881 | 0x4882 | 0x4888 | 0x4890 | 0x4892 | 0x48c0 | 0x4901
+        | 0x4902 | 0x4908 | 0x4909 | 0x490a | 0x4910 | 0x4911 | 0x4912 | 0x4940 | 0x4941
+        | 0x4942 | 0x4948 | 0x4950 | 0x4980 | 0x4981 | 0x4982 | 0x4988 | 0x4990 | 0x49c0
+        | 0x4a1a | 0x4a52 | 0x4a8a | 0x4a98 | 0x4a9a | 0x4ac2 | 0x4ac8 | 0x4ad0 | 0x4ad2
+        | 0x4b0a | 0x4b12 | 0x4b42 | 0x4b48 | 0x4b50 | 0x4b82 | 0x4b88 | 0x4b8a | 0x4b90
+        | 0x4b92 | 0x4bc2 | 0x4bc8 | 0x4bd0 | 0x4c19 | 0x4c51 | 0x4c58 | 0x4c59 | 0x4c89
+        | 0x4c91 | 0x4cc1 | 0x4cc8 | 0x4cc9 | 0x4cd0 | 0x4d09 | 0x4d18 | 0x4d19 | 0x4d41
+        | 0x4d48 | 0x4d49 | 0x4d50 | 0x4d51 | 0x4d81 | 0x4d88 | 0x4d89 | 0x4d90 | 0x4d91
+        | 0x4dc1 | 0x4dc8 | 0x4dd0 | 0x5003 | 0x5005 | 0x5006 | 0x5007 | 0x5011 | 0x5012
+        | 0x5013 | 0x5014 | 0x5015 | 0x5016 | 0x5041 | 0x5042 | 0x5044 | 0x5050 | 0x5051
+        | 0x5052 | 0x5054 | 0x5081 | 0x5082 | 0x5084 | 0x5090 | 0x5091 | 0x5092 | 0x5094
+        | 0x50c0 | 0x50d0 | 0x5101 | 0x5102 | 0x5104 | 0x5110 | 0x5111 | 0x5112 | 0x5114
+        | 0x5140 | 0x5150 | 0x5180 | 0x5190 | 0x51c0 | 0x5216 | 0x5246 | 0x5252 | 0x5256
+        | 0x5286 | 0x5294 | 0x5296 | 0x52c2 | 0x52c4 | 0x52d0 | 0x52d2 | 0x52d4 | 0x5312
+        | 0x5314 | 0x5342 | 0x5344 | 0x5350 | 0x5352 | 0x5354 | 0x5382 | 0x5384 | 0x5390
+        | 0x5392 | 0x53c2 | 0x53c4 | 0x53d0 | 0x5415 | 0x5445 | 0x5451 | 0x5455 | 0x5485
+        | 0x5491 | 0x5494 | 0x5495 | 0x54c1 | 0x54c4 | 0x54d0 | 0x54d1 | 0x54d4 | 0x5505
+        | 0x5514 | 0x5515 | 0x5541 | 0x5544 | 0x5550 | 0x5551 | 0x5554 | 0x5581 | 0x5584
+        | 0x5590 | 0x5591 | 0x5594 | 0x55c1 | 0x55c4 | 0x55d0 | 0x5794 | 0x57d4 | 0x5813
+        | 0x5851 | 0x5852 | 0x5883 | 0x5891 | 0x5893 | 0x58c1 | 0x58c2 | 0x58d0 | 0x58d2
+        | 0x5903 | 0x5912 | 0x5913 | 0x5941 | 0x5942 | 0x5950 | 0x5951 | 0x5952 | 0x5981
+        | 0x5982 | 0x5990 | 0x5991 | 0x5992 | 0x59c1 | 0x59c2 | 0x59d0 | 0x5b52 | 0x5bd2
+        | 0x5cd1 | 0x5dd1 | 0x6003 | 0x6005 | 0x6006 | 0x6007 | 0x6009 | 0x600a | 0x600b
+        | 0x600c | 0x600d | 0x6041 | 0x6048 | 0x6049 | 0x6088 | 0x60c0 | 0x60c8 | 0x6108
+        | 0x6140 | 0x6148 | 0x6180 | 0x61c0 | 0x62c2 | 0x62c4 | 0x62c8 | 0x6342 | 0x6344
+        | 0x6348 | 0x6382 | 0x6384 | 0x6388 | 0x63c2 | 0x63c4 | 0x63c8 | 0x640d | 0x6445
+        | 0x644c | 0x644d | 0x6489 | 0x648c | 0x64c1 | 0x64c4 | 0x64c8 | 0x64c9 | 0x64cc
+        | 0x6509 | 0x6541 | 0x6544 | 0x6548 | 0x6549 | 0x6581 | 0x6584 | 0x6588 | 0x65c1
+        | 0x65c4 | 0x65c8 | 0x66cc | 0x674c | 0x678c | 0x67cc | 0x680b | 0x6843 | 0x684a
+        | 0x684b | 0x6889 | 0x68c1 | 0x68c2 | 0x68c8 | 0x68c9 | 0x6909 | 0x690a | 0x6941
+        | 0x6942 | 0x6948 | 0x6949 | 0x694a | 0x6981 | 0x6982 | 0x6988 | 0x69c1 | 0x69c2
+        | 0x69c8 | 0x6aca | 0x6b4a | 0x6b8a | 0x6bca | 0x6d89 | 0x6dc9 | 0x8001 | 0x8002
+        | 0x8003 | 0x8004 | 0x8005 | 0x8020 | 0x8100 | 0x8101 | 0x8104 | 0x8120 | 0x820a
+        | 0x820c | 0x8212 | 0x8218 | 0x821a | 0x8224 | 0x8228 | 0x822c | 0x8230 | 0x8238
+        | 0x8282 | 0x8288 | 0x8290 | 0x8292 | 0x8298 | 0x8304 | 0x8308 | 0x8320 | 0x8324
+        | 0x8328 | 0x8405 | 0x8409 | 0x840c | 0x8411 | 0x8414 | 0x8418 | 0x8419 | 0x8421
+        | 0x8424 | 0x8428 | 0x842c | 0x8430 | 0x8431 | 0x8434 | 0x8438 | 0x8501 | 0x8504
+        | 0x8505 | 0x8508 | 0x8510 | 0x8511 | 0x8514 | 0x8518 | 0x8520 | 0x8521 | 0x8524
+        | 0x8528 | 0x8530 | 0x861c | 0x862c | 0x8634 | 0x863c | 0x868c | 0x8698 | 0x86a4
+        | 0x86a8 | 0x86ac | 0x86b0 | 0x86b8 | 0x870c | 0x8714 | 0x8718 | 0x8728 | 0x872c
+        | 0x8730 | 0x8734 | 0x8738 | 0x8784 | 0x87a0 | 0x87a4 | 0x8803 | 0x8809 | 0x880a
+        | 0x8811 | 0x8812 | 0x8813 | 0x8818 | 0x8819 | 0x881a | 0x8821 | 0x8822 | 0x8828
+        | 0x8830 | 0x8831 | 0x8832 | 0x8838 | 0x8881 | 0x8882 | 0x8888 | 0x8890 | 0x8891
+        | 0x8892 | 0x8898 | 0x88a0 | 0x88b0 | 0x8901 | 0x8902 | 0x8908 | 0x8910 | 0x8911
+        | 0x8912 | 0x8918 | 0x8920 | 0x8930 | 0x8980 | 0x8990 | 0x8a1a | 0x8a2a | 0x8a32
+        | 0x8a3a | 0x8a8a | 0x8a98 | 0x8a9a | 0x8aa2 | 0x8aa8 | 0x8ab0 | 0x8ab2 | 0x8ab8
+        | 0x8b0a | 0x8b12 | 0x8b18 | 0x8b1a | 0x8b28 | 0x8b30 | 0x8b38 | 0x8b82 | 0x8b90
+        | 0x8b92 | 0x8c19 | 0x8c29 | 0x8c31 | 0x8c39 | 0x8c89 | 0x8c91 | 0x8c98 | 0x8c99
+        | 0x8ca1 | 0x8ca8 | 0x8cb0 | 0x8cb1 | 0x8cb8 | 0x8d09 | 0x8d18 | 0x8d19 | 0x8d21
+        | 0x8d28 | 0x8d30 | 0x8d31 | 0x8d38 | 0x8d81 | 0x8d90 | 0x8d91 | 0x9003 | 0x9005
+        | 0x9006 | 0x9007 | 0x9011 | 0x9012 | 0x9013 | 0x9014 | 0x9015 | 0x9021 | 0x9022
+        | 0x9023 | 0x9024 | 0x9025 | 0x9081 | 0x9082 | 0x9083 | 0x9084 | 0x9085 | 0x9090
+        | 0x9091 | 0x9092 | 0x90a0 | 0x90a1 | 0x9101 | 0x9102 | 0x9103 | 0x9104 | 0x9105
+        | 0x9110 | 0x9111 | 0x9120 | 0x9121 | 0x9124 | 0x9415 | 0x9425 | 0x9431 | 0x9434
+        | 0x9485 | 0x9491 | 0x94a1 | 0x94a4 | 0x9505 | 0x9514 | 0x9515 | 0x9521 | 0x9525
+        | 0x9530 | 0x9531 | 0x9534 | 0x9581 | 0x9584 | 0x9585 | 0x9590 | 0x9591 | 0x95a0
+        | 0x95a1 | 0x95a4 | 0x9813 | 0x9823 | 0x9831 | 0x9832 | 0x9833 | 0x9883 | 0x9891
+        | 0x9893 | 0x98a1 | 0x98a2 | 0x98b0 | 0x98b1 | 0x98b2 | 0x9903 | 0x9912 | 0x9913
+        | 0x9921 | 0x9930 | 0x9931 | 0x9981 | 0x9982 | 0x9990 | 0x9991 | 0x9992 | 0x9cb1
+        | 0x9da1 | 0x9db0 | 0x9db1 | 0xa003 | 0xa005 | 0xa006 | 0xa007 | 0xa021 | 0xa022
+        | 0xa024 | 0xa025 | 0xa026 | 0xa101 | 0xa102 | 0xa104 | 0xa105 | 0xa106 | 0xa120
+        | 0xa124 | 0xa226 | 0xa22c | 0xa2a4 | 0xa306 | 0xa30c | 0xa322 | 0xa326 | 0xa328
+        | 0xa32c | 0xa384 | 0xa3a0 | 0xa3a4 | 0xa425 | 0xa42c | 0xa4a4 | 0xa505 | 0xa50c
+        | 0xa521 | 0xa525 | 0xa528 | 0xa52c | 0xa584 | 0xa5a0 | 0xa5a4 | 0xa6ac | 0xa78c
+        | 0xa7a8 | 0xa7ac | 0xb023 | 0xb025 | 0xb026 | 0xb027 | 0xb083 | 0xb085 | 0xb086
+        | 0xb087 | 0xb0a1 | 0xb0a4 | 0xb0a5 | 0xb103 | 0xb105 | 0xb106 | 0xb107 | 0xb121
+        | 0xb122 | 0xb125 | 0xb126 | 0xb184 | 0xb1a0 | 0xb1a4 | 0xb4a5 | 0xb585 | 0xb5a1
+        | 0xb5a5 | 0xc003 | 0xc005 | 0xc006 | 0xc007 | 0xc011 | 0xc012 | 0xc013 | 0xc014
+        | 0xc015 | 0xc016 | 0xc081 | 0xc082 | 0xc083 | 0xc084 | 0xc086 | 0xc090 | 0xc091
+        | 0xc092 | 0xc101 | 0xc102 | 0xc103 | 0xc104 | 0xc105 | 0xc110 | 0xc111 | 0xc112
+        | 0xc180 | 0xc190 | 0xc216 | 0xc21a | 0xc286 | 0xc28a | 0xc294 | 0xc296 | 0xc298
+        | 0xc29a | 0xc312 | 0xc382 | 0xc390 | 0xc392 | 0xc415 | 0xc419 | 0xc491 | 0xc505
+        | 0xc509 | 0xc514 | 0xc515 | 0xc518 | 0xc519 | 0xc581 | 0xc590 | 0xc591 | 0xc813
+        | 0xc819 | 0xc81a | 0xc883 | 0xc88a | 0xc891 | 0xc893 | 0xc898 | 0xc89a | 0xc903
+        | 0xc909 | 0xc90a | 0xc912 | 0xc913 | 0xc918 | 0xc919 | 0xc91a | 0xc981 | 0xc982
+        | 0xc988 | 0xc990 | 0xc991 | 0xc992 | 0xc998 | 0xcb1a | 0xcb8a | 0xcb98 | 0xcb9a
+        | 0xcc99 | 0xcd89 | 0xcd98 | 0xcd99 | 0xd013 | 0xd015 | 0xd016 | 0xd017 | 0xd083
+        | 0xd085 | 0xd086 | 0xd087 | 0xd091 | 0xd093 | 0xd094 | 0xd095 | 0xd096 | 0xd103
+        | 0xd105 | 0xd106 | 0xd107 | 0xd112 | 0xd113 | 0xd114 | 0xd115 | 0xd181 | 0xd182
+        | 0xd190 | 0xd191 | 0xd192 | 0xd495 | 0xd585 | 0xd594 | 0xd595 | 0xd983 | 0xd993
+        | 0xe00b | 0xe00d | 0xe00e | 0xe00f | 0xe083 | 0xe085 | 0xe086 | 0xe087 | 0xe103
+        | 0xe105 | 0xe106 | 0xe107 | 0x10001 | 0x10004 | 0x10005 | 0x10008 | 0x10010 | 0x10011
+        | 0x10014 | 0x10018 | 0x10020 | 0x10030 | 0x10040 | 0x10041 | 0x10048 | 0x10050
+        | 0x10100 | 0x10104 | 0x10110 | 0x10120 | 0x1020c | 0x10214 | 0x10218 | 0x1021c
+        | 0x10224 | 0x10228 | 0x1022c | 0x10230 | 0x10234 | 0x10238 | 0x10244 | 0x10248
+        | 0x10250 | 0x10254 | 0x10258 | 0x10260 | 0x10264 | 0x10270 | 0x10304 | 0x10308
+        | 0x10310 | 0x10314 | 0x10320 | 0x10324 | 0x10328 | 0x10330 | 0x10340 | 0x10344
+        | 0x10405 | 0x10409 | 0x1040c | 0x10411 | 0x10414 | 0x10415 | 0x10418 | 0x10419
+        | 0x1041c | 0x10421 | 0x10424 | 0x10428 | 0x10430 | 0x10431 | 0x10434 | 0x10438
+        | 0x10441 | 0x10444 | 0x10448 | 0x10449 | 0x10450 | 0x10451 | 0x10454 | 0x10458
+        | 0x10460 | 0x10470 | 0x10501 | 0x10504 | 0x10508 | 0x10510 | 0x10511 | 0x10514
+        | 0x10518 | 0x10520 | 0x10524 | 0x10530 | 0x10540 | 0x10550 | 0x1061c | 0x1062c
+        | 0x10634 | 0x1063c | 0x1064c | 0x10658 | 0x1065c | 0x10664 | 0x10668 | 0x10670
+        | 0x10674 | 0x10678 | 0x1070c | 0x10714 | 0x10718 | 0x1071c | 0x10728 | 0x1072c
+        | 0x10730 | 0x10734 | 0x10738 | 0x10744 | 0x10750 | 0x10754 | 0x10760 | 0x10764
+        | 0x10809 | 0x10811 | 0x10818 | 0x10819 | 0x10821 | 0x10828 | 0x10829 | 0x10830
+        | 0x10831 | 0x10838 | 0x10841 | 0x10848 | 0x10849 | 0x10850 | 0x10851 | 0x10858
+        | 0x10860 | 0x10868 | 0x10901 | 0x10908 | 0x10909 | 0x10910 | 0x10911 | 0x10918
+        | 0x10920 | 0x10930 | 0x10940 | 0x10941 | 0x10a1a | 0x10a2a | 0x10a32 | 0x10a3a
+        | 0x10a58 | 0x10a68 | 0x10a70 | 0x10a78 | 0x10b18 | 0x10b28 | 0x10b30 | 0x10b38
+        | 0x10c19 | 0x10c29 | 0x10c31 | 0x10c39 | 0x10c51 | 0x10c58 | 0x10c59 | 0x10c61
+        | 0x10c68 | 0x10c69 | 0x10c70 | 0x10c71 | 0x10c78 | 0x10d09 | 0x10d18 | 0x10d19
+        | 0x10d21 | 0x10d28 | 0x10d30 | 0x10d31 | 0x10d38 | 0x10d41 | 0x10d48 | 0x10d49
+        | 0x10d50 | 0x10d51 | 0x11003 | 0x11005 | 0x11006 | 0x11007 | 0x11011 | 0x11012
+        | 0x11013 | 0x11014 | 0x11015 | 0x11016 | 0x11021 | 0x11022 | 0x11024 | 0x11025
+        | 0x11026 | 0x11030 | 0x11034 | 0x11041 | 0x11042 | 0x11044 | 0x11045 | 0x11046
+        | 0x11050 | 0x11051 | 0x11054 | 0x11060 | 0x11064 | 0x11101 | 0x11102 | 0x11103
+        | 0x11104 | 0x11105 | 0x11106 | 0x11110 | 0x11111 | 0x11114 | 0x11120 | 0x11121
+        | 0x11124 | 0x11130 | 0x11140 | 0x11144 | 0x11150 | 0x11216 | 0x11226 | 0x11234
+        | 0x11246 | 0x11252 | 0x11256 | 0x11262 | 0x11264 | 0x11266 | 0x11270 | 0x11274
+        | 0x11306 | 0x11314 | 0x11322 | 0x11326 | 0x11330 | 0x11334 | 0x11342 | 0x11344
+        | 0x11346 | 0x11350 | 0x11354 | 0x11360 | 0x11364 | 0x11415 | 0x11425 | 0x11431
+        | 0x11434 | 0x11435 | 0x11445 | 0x11451 | 0x11455 | 0x11461 | 0x11464 | 0x11470
+        | 0x11471 | 0x11474 | 0x11505 | 0x11514 | 0x11515 | 0x11521 | 0x11525 | 0x11530
+        | 0x11531 | 0x11534 | 0x11541 | 0x11544 | 0x11550 | 0x11551 | 0x11554 | 0x11560
+        | 0x11564 | 0x11570 | 0x11770 | 0x11774 | 0x11813 | 0x11831 | 0x11851 | 0x11903
+        | 0x11912 | 0x11913 | 0x11921 | 0x11930 | 0x11931 | 0x11941 | 0x11950 | 0x11951
+        | 0x11c71 | 0x11d61 | 0x11d70 | 0x11d71 | 0x12003 | 0x12005 | 0x12006 | 0x12007
+        | 0x12009 | 0x1200a | 0x1200b | 0x12022 | 0x12024 | 0x12026 | 0x12041 | 0x12042
+        | 0x12043 | 0x12048 | 0x12049 | 0x12102 | 0x12104 | 0x12106 | 0x12120 | 0x12124
+        | 0x12226 | 0x1222c | 0x12264 | 0x12306 | 0x1230c | 0x12322 | 0x12326 | 0x12328
+        | 0x1232c | 0x12344 | 0x12360 | 0x12364 | 0x1280b | 0x12829 | 0x12843 | 0x1284a
+        | 0x1284b | 0x12861 | 0x12868 | 0x12869 | 0x12909 | 0x12941 | 0x12948 | 0x12949
+        | 0x13023 | 0x13025 | 0x13026 | 0x13027 | 0x13043 | 0x13045 | 0x13046 | 0x13047
+        | 0x13062 | 0x13064 | 0x13066 | 0x13103 | 0x13105 | 0x13106 | 0x13107 | 0x13121
+        | 0x13122 | 0x13125 | 0x13126 | 0x13144 | 0x13160 | 0x13164 | 0x13266 | 0x13346
+        | 0x13362 | 0x13366 | 0x14003 | 0x14005 | 0x14006 | 0x14007 | 0x14009 | 0x1400a
+        | 0x1400b | 0x1400c | 0x1400d | 0x14011 | 0x14012 | 0x14013 | 0x14014 | 0x14015
+        | 0x14016 | 0x14018 | 0x14019 | 0x14041 | 0x14042 | 0x14043 | 0x14044 | 0x14045
+        | 0x14046 | 0x14048 | 0x14049 | 0x1404c | 0x14050 | 0x14051 | 0x14054 | 0x14058
+        | 0x14101 | 0x14102 | 0x14103 | 0x14104 | 0x14105 | 0x14108 | 0x14109 | 0x14110
+        | 0x14111 | 0x14114 | 0x14140 | 0x14141 | 0x14150 | 0x14216 | 0x1421c | 0x14246
+        | 0x1424c | 0x14252 | 0x14256 | 0x14258 | 0x1425c | 0x14314 | 0x14344 | 0x14350
+        | 0x14354 | 0x1440d | 0x14415 | 0x14419 | 0x1441c | 0x1441d | 0x14445 | 0x1444c
+        | 0x1444d | 0x14451 | 0x14455 | 0x14458 | 0x14459 | 0x1445c | 0x14505 | 0x14509
+        | 0x1450c | 0x14514 | 0x14515 | 0x14518 | 0x14519 | 0x1451c | 0x14541 | 0x14544
+        | 0x14548 | 0x14549 | 0x14550 | 0x14551 | 0x14554 | 0x14558 | 0x1471c | 0x1474c
+        | 0x14758 | 0x1475c | 0x1480b | 0x14813 | 0x14819 | 0x14843 | 0x1484a | 0x1484b
+        | 0x14851 | 0x14858 | 0x14859 | 0x14903 | 0x14909 | 0x1490a | 0x1490b | 0x14912
+        | 0x14913 | 0x14918 | 0x14919 | 0x14941 | 0x14942 | 0x14943 | 0x14948 | 0x14949
+        | 0x14950 | 0x14951 | 0x14d58 | 0x14d59 | 0x15013 | 0x15015 | 0x15016 | 0x15017
+        | 0x15043 | 0x15045 | 0x15046 | 0x15047 | 0x15051 | 0x15052 | 0x15053 | 0x15055
+        | 0x15056 | 0x15103 | 0x15105 | 0x15106 | 0x15107 | 0x15112 | 0x15113 | 0x15114
+        | 0x15115 | 0x15116 | 0x15141 | 0x15142 | 0x15144 | 0x15150 | 0x15151 | 0x15152
+        | 0x15154 | 0x15316 | 0x15346 | 0x15352 | 0x15356 | 0x15545 | 0x15555 | 0x15853
+        | 0x15943 | 0x15952 | 0x15953 | 0x1600b | 0x1600d | 0x1600e | 0x1600f | 0x16043
+        | 0x16045 | 0x16046 | 0x16047 | 0x1604a | 0x1604b | 0x1604c | 0x1604d | 0x16103
+        | 0x16105 | 0x16106 | 0x16107 | 0x16109 | 0x1610a | 0x1610b | 0x16141 | 0x16148
+        | 0x16149 | 0x1690b | 0x16943 | 0x1694a | 0x1694b | 0x18003 | 0x18005 | 0x18006
+        | 0x18007 | 0x1800a | 0x1800c | 0x18011 | 0x18014 | 0x18018 | 0x18021 | 0x18022
+        | 0x18024 | 0x18028 | 0x18030 | 0x18038 | 0x18101 | 0x18102 | 0x18103 | 0x18104
+        | 0x18105 | 0x18106 | 0x18108 | 0x1810a | 0x1810c | 0x18110 | 0x18111 | 0x18114
+        | 0x18118 | 0x18120 | 0x18121 | 0x18122 | 0x18124 | 0x18128 | 0x18130 | 0x1821a
+        | 0x1821c | 0x18226 | 0x1822a | 0x1822c | 0x18232 | 0x18234 | 0x1823a | 0x1823c
+        | 0x18306 | 0x1830a | 0x1830c | 0x18314 | 0x18318 | 0x18322 | 0x18326 | 0x18328
+        | 0x1832a | 0x1832c | 0x18330 | 0x18334 | 0x18338 | 0x18415 | 0x18419 | 0x1841c
+        | 0x18425 | 0x18429 | 0x1842c | 0x18431 | 0x18434 | 0x18439 | 0x1843c | 0x18505
+        | 0x18509 | 0x1850c | 0x18514 | 0x18515 | 0x18518 | 0x18519 | 0x1851c | 0x18521
+        | 0x18525 | 0x18528 | 0x1852c | 0x18530 | 0x18531 | 0x18534 | 0x18538 | 0x1871c
+        | 0x1873c | 0x18813 | 0x18819 | 0x1881a | 0x18829 | 0x1882a | 0x18831 | 0x18832
+        | 0x18839 | 0x1883a | 0x18903 | 0x18909 | 0x1890a | 0x18912 | 0x18913 | 0x18918
+        | 0x18919 | 0x1891a | 0x18921 | 0x18922 | 0x18928 | 0x18930 | 0x18931 | 0x18932
+        | 0x18938 | 0x18b1a | 0x18b2a | 0x18b32 | 0x18b3a | 0x18d29 | 0x18d39 | 0x19013
+        | 0x19015 | 0x19016 | 0x19017 | 0x19023 | 0x19025 | 0x19026 | 0x19027 | 0x19031
+        | 0x19034 | 0x19103 | 0x19105 | 0x19106 | 0x19107 | 0x19112 | 0x19113 | 0x19114
+        | 0x19115 | 0x19121 | 0x19122 | 0x19123 | 0x19125 | 0x19126 | 0x19130 | 0x19131
+        | 0x19134 | 0x19435 | 0x19535 | 0x19833 | 0x19923 | 0x19932 | 0x19933 | 0x1a00b
+        | 0x1a00d | 0x1a00e | 0x1a00f | 0x1a023 | 0x1a025 | 0x1a026 | 0x1a027 | 0x1a02c
+        | 0x1a103 | 0x1a105 | 0x1a106 | 0x1a107 | 0x1a10a | 0x1a10c | 0x1a10e | 0x1a121
+        | 0x1a122 | 0x1a125 | 0x1a126 | 0x1a128 | 0x1a12c | 0x1a22e | 0x1a30e | 0x1a32a
+        | 0x1a32e | 0x1b123 | 0x1b127 | 0x1c00b | 0x1c00d | 0x1c00e | 0x1c00f | 0x1c013
+        | 0x1c015 | 0x1c016 | 0x1c017 | 0x1c019 | 0x1c103 | 0x1c105 | 0x1c106 | 0x1c107
+        | 0x1c109 | 0x1c10a | 0x1c10b | 0x1c10c | 0x1c10d | 0x1c112 | 0x1c113 | 0x1c114
+        | 0x1c115 | 0x1c118 | 0x1c119 | 0x1c41d | 0x1c50d | 0x1c51c | 0x1c51d | 0x1c81b
+        | 0x1c90b | 0x1c91a | 0x1c91b | 0x1d116 | 0x1d117 | 0x1e10b | 0x1e10d | 0x1e10e
+        | 0x1e10f | 0x20001 | 0x20002 | 0x20004 | 0x20005 | 0x20006 | 0x20008 | 0x20040
+        | 0x20041 | 0x20044 | 0x20048 | 0x20206 | 0x2020a | 0x2020c | 0x20212 | 0x20214
+        | 0x20216 | 0x20218 | 0x2021a | 0x2021c | 0x20222 | 0x20224 | 0x20228 | 0x20230
+        | 0x20232 | 0x20234 | 0x20238 | 0x20242 | 0x20244 | 0x20248 | 0x20250 | 0x20252
+        | 0x20254 | 0x20258 | 0x20260 | 0x20270 | 0x20282 | 0x20284 | 0x20288 | 0x20290
+        | 0x20292 | 0x20294 | 0x20298 | 0x202a0 | 0x202b0 | 0x202c0 | 0x202d0 | 0x20405
+        | 0x20409 | 0x2040c | 0x20411 | 0x20414 | 0x20418 | 0x20419 | 0x2041c | 0x20421
+        | 0x20424 | 0x20428 | 0x20429 | 0x20430 | 0x20434 | 0x20438 | 0x20441 | 0x20444
+        | 0x20445 | 0x20448 | 0x20449 | 0x2044c | 0x20450 | 0x20451 | 0x20454 | 0x20458
+        | 0x20460 | 0x20468 | 0x20470 | 0x2061c | 0x2062c | 0x20634 | 0x2063c | 0x2064c
+        | 0x20658 | 0x2065c | 0x20664 | 0x20668 | 0x20670 | 0x20674 | 0x20678 | 0x2068c
+        | 0x20694 | 0x20698 | 0x2069c | 0x206a4 | 0x206a8 | 0x206b0 | 0x206b4 | 0x206b8
+        | 0x206c4 | 0x206d0 | 0x206d4 | 0x20809 | 0x20812 | 0x20818 | 0x20821 | 0x20822
+        | 0x20828 | 0x20829 | 0x20830 | 0x20832 | 0x20838 | 0x20841 | 0x20848 | 0x20849
+        | 0x20860 | 0x20868 | 0x20882 | 0x20890 | 0x20892 | 0x208a0 | 0x208b0 | 0x20a1a
+        | 0x20a2a | 0x20a32 | 0x20a3a | 0x20a52 | 0x20a58 | 0x20a62 | 0x20a68 | 0x20a70
+        | 0x20a72 | 0x20a78 | 0x20a8a | 0x20a98 | 0x20a9a | 0x20aa2 | 0x20aa8 | 0x20ab0
+        | 0x20ab2 | 0x20ab8 | 0x20ac2 | 0x20ad0 | 0x20ad2 | 0x20c19 | 0x20c29 | 0x20c31
+        | 0x20c39 | 0x20c51 | 0x20c58 | 0x20c59 | 0x20c61 | 0x20c68 | 0x20c69 | 0x20c70
+        | 0x20c78 | 0x20c89 | 0x20c98 | 0x20ca1 | 0x20ca8 | 0x20ca9 | 0x20cb0 | 0x20cb8
+        | 0x20cc1 | 0x20cc8 | 0x20cc9 | 0x21003 | 0x21005 | 0x21006 | 0x21007 | 0x21011
+        | 0x21012 | 0x21013 | 0x21014 | 0x21015 | 0x21016 | 0x21041 | 0x21042 | 0x21044
+        | 0x21045 | 0x21046 | 0x21050 | 0x21052 | 0x21054 | 0x21081 | 0x21082 | 0x21083
+        | 0x21084 | 0x21086 | 0x21090 | 0x21092 | 0x21094 | 0x210c0 | 0x210d0 | 0x21216
+        | 0x21232 | 0x21234 | 0x21246 | 0x21252 | 0x21256 | 0x21262 | 0x21264 | 0x21270
+        | 0x21272 | 0x21274 | 0x21286 | 0x21294 | 0x21296 | 0x212a2 | 0x212b0 | 0x212b2
+        | 0x212c2 | 0x212c4 | 0x212d0 | 0x212d2 | 0x212d4 | 0x212e0 | 0x212f0 | 0x21415
+        | 0x21434 | 0x21445 | 0x21451 | 0x21455 | 0x21464 | 0x21470 | 0x21474 | 0x21494
+        | 0x214c4 | 0x214d0 | 0x214d4 | 0x216b4 | 0x216e4 | 0x216f0 | 0x216f4 | 0x21813
+        | 0x21832 | 0x21852 | 0x21883 | 0x21891 | 0x21893 | 0x218a2 | 0x218b0 | 0x218b2
+        | 0x218c2 | 0x218d0 | 0x218d2 | 0x21a72 | 0x21ae2 | 0x21af0 | 0x21af2 | 0x22003
+        | 0x22005 | 0x22006 | 0x22007 | 0x22009 | 0x2200a | 0x2200b | 0x2200c | 0x2200d
+        | 0x22041 | 0x22042 | 0x22043 | 0x22044 | 0x22045 | 0x22048 | 0x22049 | 0x2240d
+        | 0x22429 | 0x22445 | 0x2244c | 0x2244d | 0x22461 | 0x22468 | 0x22469 | 0x22489
+        | 0x224c1 | 0x224c8 | 0x224c9 | 0x2280b | 0x22829 | 0x22843 | 0x2284a | 0x2284b
+        | 0x22861 | 0x22868 | 0x22869 | 0x22889 | 0x228c1 | 0x228c8 | 0x228c9 | 0x22ca9
+        | 0x22ce1 | 0x22ce8 | 0x22ce9 | 0x23023 | 0x23025 | 0x23026 | 0x23027 | 0x23043
+        | 0x23045 | 0x23046 | 0x23047 | 0x23083 | 0x23085 | 0x23086 | 0x23087 | 0x24003
+        | 0x24005 | 0x24006 | 0x24007 | 0x24009 | 0x2400a | 0x2400c | 0x2400d | 0x2400e
+        | 0x24011 | 0x24012 | 0x24014 | 0x24015 | 0x24016 | 0x24041 | 0x24042 | 0x24044
+        | 0x24045 | 0x24046 | 0x24048 | 0x24049 | 0x2404c | 0x24050 | 0x24054 | 0x24081
+        | 0x24082 | 0x24084 | 0x24085 | 0x24086 | 0x24088 | 0x2408c | 0x24090 | 0x24092
+        | 0x24094 | 0x2420e | 0x24216 | 0x2421a | 0x2421c | 0x2421e | 0x24246 | 0x2424c
+        | 0x24252 | 0x24256 | 0x24258 | 0x2425c | 0x24286 | 0x2428a | 0x2428c | 0x24294
+        | 0x24296 | 0x24298 | 0x2429a | 0x2429c | 0x242c2 | 0x242c4 | 0x242d0 | 0x242d2
+        | 0x242d4 | 0x2440d | 0x24415 | 0x24419 | 0x2441c | 0x24445 | 0x2444c | 0x2444d
+        | 0x24451 | 0x24455 | 0x24458 | 0x24459 | 0x2445c | 0x24485 | 0x24489 | 0x2448c
+        | 0x24494 | 0x244c1 | 0x244c4 | 0x244c5 | 0x244c8 | 0x244c9 | 0x244cc | 0x244d0
+        | 0x244d4 | 0x2469c | 0x246cc | 0x246d8 | 0x246dc | 0x25013 | 0x25015 | 0x25016
+        | 0x25017 | 0x25043 | 0x25045 | 0x25046 | 0x25047 | 0x25051 | 0x25052 | 0x25055
+        | 0x25056 | 0x25083 | 0x25085 | 0x25086 | 0x25087 | 0x25091 | 0x25093 | 0x25094
+        | 0x25095 | 0x25096 | 0x250c2 | 0x250c4 | 0x250d0 | 0x250d2 | 0x250d4 | 0x252c6
+        | 0x252d6 | 0x25495 | 0x254c5 | 0x254d1 | 0x254d5 | 0x2600b | 0x2600d | 0x2600e
+        | 0x2600f | 0x26043 | 0x26045 | 0x26046 | 0x26047 | 0x2604a | 0x2604b | 0x2604c
+        | 0x2604d | 0x26083 | 0x26085 | 0x26086 | 0x26087 | 0x26089 | 0x2608c | 0x2608d
+        | 0x260c1 | 0x260c8 | 0x260c9 | 0x2648d | 0x264c5 | 0x264cc | 0x264cd | 0x28003
+        | 0x28005 | 0x28006 | 0x28007 | 0x28012 | 0x28018 | 0x28028 | 0x28030 | 0x28038
+        | 0x28081 | 0x28082 | 0x28083 | 0x28084 | 0x28086 | 0x28088 | 0x28090 | 0x28092
+        | 0x28098 | 0x280a0 | 0x280b0 | 0x28216 | 0x2821a | 0x2821c | 0x2822a | 0x2822c
+        | 0x28232 | 0x28234 | 0x2823a | 0x2823c | 0x28286 | 0x2828a | 0x2828c | 0x28294
+        | 0x28296 | 0x28298 | 0x2829a | 0x2829c | 0x282a2 | 0x282a8 | 0x282b0 | 0x282b2
+        | 0x282b8 | 0x28419 | 0x2841c | 0x28429 | 0x2842c | 0x28431 | 0x28434 | 0x28439
+        | 0x2843c | 0x28498 | 0x284a8 | 0x284b0 | 0x284b8 | 0x2869c | 0x286ac | 0x286b4
+        | 0x286bc | 0x28813 | 0x28819 | 0x2881a | 0x28829 | 0x2882a | 0x28831 | 0x28832
+        | 0x28839 | 0x2883a | 0x28883 | 0x2888a | 0x28891 | 0x28893 | 0x28898 | 0x2889a
+        | 0x288a1 | 0x288a2 | 0x288a8 | 0x288b0 | 0x288b1 | 0x288b2 | 0x288b8 | 0x28aaa
+        | 0x28aba | 0x28c99 | 0x28ca9 | 0x28cb1 | 0x28cb9 | 0x29013 | 0x29015 | 0x29016
+        | 0x29017 | 0x29023 | 0x29025 | 0x29026 | 0x29027 | 0x29032 | 0x29083 | 0x29085
+        | 0x29086 | 0x29087 | 0x29091 | 0x29093 | 0x29094 | 0x29096 | 0x290a1 | 0x290a2
+        | 0x290a3 | 0x290b0 | 0x290b2 | 0x29833 | 0x298a3 | 0x298b1 | 0x298b3 | 0x2a00b
+        | 0x2a00d | 0x2a00e | 0x2a00f | 0x2a023 | 0x2a025 | 0x2a026 | 0x2a027 | 0x2a083
+        | 0x2a085 | 0x2a086 | 0x2a087 | 0x2b0a3 | 0x2b0a5 | 0x2b0a6 | 0x2b0a7 | 0x2c00b
+        | 0x2c00d | 0x2c00e | 0x2c00f | 0x2c013 | 0x2c015 | 0x2c016 | 0x2c017 | 0x2c01a
+        | 0x2c083 | 0x2c085 | 0x2c086 | 0x2c087 | 0x2c08a | 0x2c08c | 0x2c08e | 0x2c091
+        | 0x2c093 | 0x2c094 | 0x2c096 | 0x2c098 | 0x2c09a | 0x2c21e | 0x2c28e | 0x2c29c
+        | 0x2c29e | 0x2d095 | 0x2d097 | 0x2e08b | 0x2e08d | 0x2e08e | 0x2e08f | 0x30003
+        | 0x30005 | 0x30006 | 0x30007 | 0x30009 | 0x3000a | 0x3000c | 0x30011 | 0x30014
+        | 0x30018 | 0x30021 | 0x30022 | 0x30028 | 0x30030 | 0x30038 | 0x30041 | 0x30042
+        | 0x30043 | 0x30044 | 0x30045 | 0x30046 | 0x30048 | 0x30049 | 0x3004a | 0x3004c
+        | 0x30050 | 0x30051 | 0x30054 | 0x30058 | 0x30060 | 0x30061 | 0x30062 | 0x30068
+        | 0x30070 | 0x30216 | 0x3021a | 0x3021c | 0x3022a | 0x3022c | 0x30232 | 0x30234
+        | 0x3023a | 0x3023c | 0x30246 | 0x3024a | 0x3024c | 0x30252 | 0x30256 | 0x30258
+        | 0x3025a | 0x3025c | 0x30262 | 0x30264 | 0x30268 | 0x30270 | 0x30272 | 0x30274
+        | 0x30278 | 0x3040d | 0x30415 | 0x30419 | 0x3041c | 0x30429 | 0x3042c | 0x30431
+        | 0x30434 | 0x30439 | 0x3043c | 0x30445 | 0x3044c | 0x3044d | 0x30451 | 0x30455
+        | 0x30458 | 0x30459 | 0x3045c | 0x30461 | 0x30464 | 0x30468 | 0x30469 | 0x30470
+        | 0x30471 | 0x30474 | 0x30478 | 0x3066c | 0x3067c | 0x3080b | 0x30819 | 0x3081a
+        | 0x30829 | 0x3082a | 0x30831 | 0x30832 | 0x30839 | 0x3083a | 0x30843 | 0x3084a
+        | 0x3084b | 0x30851 | 0x30858 | 0x30859 | 0x30861 | 0x30862 | 0x30868 | 0x30869
+        | 0x3086a | 0x30870 | 0x30878 | 0x30a5a | 0x30a6a | 0x30a72 | 0x30a7a | 0x30c71
+        | 0x30c79 | 0x31013 | 0x31015 | 0x31016 | 0x31017 | 0x31023 | 0x31025 | 0x31026
+        | 0x31027 | 0x31034 | 0x31043 | 0x31045 | 0x31046 | 0x31047 | 0x31051 | 0x31052
+        | 0x31055 | 0x31056 | 0x31061 | 0x31062 | 0x31064 | 0x31065 | 0x31066 | 0x31070
+        | 0x31074 | 0x31236 | 0x31266 | 0x31272 | 0x31276 | 0x31435 | 0x31465 | 0x31471
+        | 0x31475 | 0x3200b | 0x3200d | 0x3200e | 0x3200f | 0x32023 | 0x32025 | 0x32026
+        | 0x32027 | 0x32029 | 0x32043 | 0x32045 | 0x32046 | 0x32047 | 0x3204a | 0x3204b
+        | 0x3204c | 0x3204d | 0x32061 | 0x32062 | 0x32063 | 0x32068 | 0x32069 | 0x3282b
+        | 0x32863 | 0x3286a | 0x3286b | 0x33063 | 0x33065 | 0x33066 | 0x33067 | 0x3400b
+        | 0x3400d | 0x3400e | 0x3400f | 0x34013 | 0x34015 | 0x34016 | 0x34017 | 0x34019
+        | 0x3401c | 0x34043 | 0x34045 | 0x34046 | 0x34047 | 0x3404a | 0x3404b | 0x3404c
+        | 0x3404d | 0x3404e | 0x34051 | 0x34052 | 0x34055 | 0x34056 | 0x34058 | 0x34059
+        | 0x3405c | 0x3421e | 0x3424e | 0x3425a | 0x3425e | 0x3441d | 0x3445d | 0x35053
+        | 0x35057 | 0x3604e | 0x3604f => Some(1),
+        0x206 | 0x222 | 0x228 | 0x248 | 0x282 | 0x288 | 0x2a0 | 0x320 | 0x380 | 0x428 | 0x448
+        | 0x520 | 0x648 | 0x64c | 0x658 | 0x668 | 0x670 | 0x698 | 0x6a8 | 0x6b0 | 0x6c8 | 0x6d0
+        | 0x6e0 | 0x718 | 0x728 | 0x730 | 0x748 | 0x750 | 0x760 | 0x788 | 0x790 | 0x7a0 | 0x803
+        | 0x80a | 0x828 | 0x848 | 0x882 | 0x888 | 0x8a0 | 0x8c0 | 0x920 | 0xa2a | 0xa48 | 0xa4a
+        | 0xa58 | 0xa68 | 0xa70 | 0xa88 | 0xa8a | 0xa98 | 0xaa0 | 0xaa2 | 0xaa8 | 0xab0 | 0xac8
+        | 0xad0 | 0xae0 | 0xb18 | 0xb20 | 0xb22 | 0xb28 | 0xb30 | 0xb48 | 0xb50 | 0xb60 | 0xb88
+        | 0xb90 | 0xba0 | 0xc58 | 0xc68 | 0xc70 | 0xc98 | 0xca8 | 0xcb0 | 0xcc8 | 0xcd0 | 0xce0
+        | 0xd18 | 0xd20 | 0xd21 | 0xd28 | 0xd30 | 0xd48 | 0xd50 | 0xd60 | 0xd88 | 0xd90 | 0xda0
+        | 0xe58 | 0xe68 | 0xe70 | 0xe98 | 0xea8 | 0xeb0 | 0xec8 | 0xed0 | 0xee0 | 0xf18 | 0xf28
+        | 0xf30 | 0xf48 | 0xf50 | 0xf60 | 0xf88 | 0xf90 | 0xfa0 | 0x1006 | 0x1082 | 0x1180
+        | 0x1206 | 0x1216 | 0x1226 | 0x1232 | 0x1234 | 0x1246 | 0x1286 | 0x1294 | 0x12a2
+        | 0x12a4 | 0x12b0 | 0x1306 | 0x1312 | 0x1314 | 0x1322 | 0x1330 | 0x1382 | 0x1384
+        | 0x1390 | 0x13a0 | 0x1445 | 0x14b0 | 0x16b0 | 0x16b4 | 0x16f0 | 0x1770 | 0x1794
+        | 0x17b0 | 0x1843 | 0x1883 | 0x1903 | 0x1922 | 0x1930 | 0x19a0 | 0x1af0 | 0x1b30
+        | 0x1b32 | 0x1b70 | 0x1ba2 | 0x1bb0 | 0x1cf0 | 0x1d70 | 0x1db0 | 0x1ef0 | 0x1f70
+        | 0x1fb0 | 0x2028 | 0x2082 | 0x220e | 0x2226 | 0x2228 | 0x222a | 0x222c | 0x2246
+        | 0x224a | 0x224c | 0x2262 | 0x2264 | 0x2268 | 0x2282 | 0x2286 | 0x228a | 0x228c
+        | 0x22a2 | 0x22a4 | 0x22a8 | 0x22c2 | 0x22c4 | 0x22c8 | 0x22e0 | 0x2328 | 0x2382
+        | 0x240d | 0x2425 | 0x2428 | 0x2429 | 0x242c | 0x2445 | 0x244c | 0x2461 | 0x2464
+        | 0x2468 | 0x24a8 | 0x2505 | 0x2509 | 0x250c | 0x2521 | 0x2528 | 0x2541 | 0x2544
+        | 0x2548 | 0x2560 | 0x264c | 0x2668 | 0x266c | 0x26a8 | 0x26ac | 0x26cc | 0x26e4
+        | 0x26e8 | 0x274c | 0x2768 | 0x27a8 | 0x280b | 0x2823 | 0x2828 | 0x2829 | 0x282a
+        | 0x2868 | 0x2882 | 0x2883 | 0x2889 | 0x288a | 0x28a1 | 0x28a2 | 0x28a8 | 0x28c2
+        | 0x2903 | 0x2909 | 0x290a | 0x2921 | 0x2922 | 0x2928 | 0x2981 | 0x2982 | 0x2988
+        | 0x29a0 | 0x2a2a | 0x2a68 | 0x2a6a | 0x2a8a | 0x2aa2 | 0x2aa8 | 0x2aaa | 0x2aca
+        | 0x2ae2 | 0x2ae8 | 0x2b28 | 0x2b2a | 0x2b68 | 0x2b8a | 0x2ba2 | 0x2ba8 | 0x2ca8
+        | 0x2ca9 | 0x2ce8 | 0x2d21 | 0x2d28 | 0x2d29 | 0x2d61 | 0x2d68 | 0x2d89 | 0x2da1
+        | 0x2da8 | 0x2ee8 | 0x2f68 | 0x2fa8 | 0x3043 | 0x3045 | 0x3046 | 0x3082 | 0x3083
+        | 0x3085 | 0x3086 | 0x30a2 | 0x30c1 | 0x30c2 | 0x30c4 | 0x3103 | 0x3105 | 0x3106
+        | 0x3141 | 0x3142 | 0x3144 | 0x3181 | 0x3182 | 0x3184 | 0x3246 | 0x3266 | 0x3286
+        | 0x32a2 | 0x32a6 | 0x32c6 | 0x32e2 | 0x32e4 | 0x3346 | 0x3386 | 0x33a2 | 0x3445
+        | 0x3465 | 0x34c5 | 0x3545 | 0x3561 | 0x3585 | 0x3883 | 0x38a3 | 0x38c3 | 0x3903
+        | 0x3923 | 0x3943 | 0x3983 | 0x39a1 | 0x39a2 | 0x4003 | 0x4082 | 0x40c0 | 0x4246
+        | 0x424a | 0x4258 | 0x4286 | 0x42c8 | 0x4306 | 0x4498 | 0x4505 | 0x46d8 | 0x4758
+        | 0x4798 | 0x4803 | 0x480b | 0x4813 | 0x4819 | 0x481a | 0x4843 | 0x484a | 0x4851
+        | 0x4852 | 0x4858 | 0x4883 | 0x4889 | 0x488a | 0x4891 | 0x4898 | 0x48c1 | 0x48c2
+        | 0x48c8 | 0x48d0 | 0x4903 | 0x4a58 | 0x4a5a | 0x4aca | 0x4ad8 | 0x4b58 | 0x4b98
+        | 0x4c98 | 0x4c99 | 0x4cd1 | 0x4cd8 | 0x4d58 | 0x4d98 | 0x4ed8 | 0x4f58 | 0x4f98
+        | 0x5043 | 0x5045 | 0x5046 | 0x5083 | 0x5085 | 0x5086 | 0x50c1 | 0x50c2 | 0x50c4
+        | 0x5103 | 0x5105 | 0x5106 | 0x5141 | 0x5142 | 0x5144 | 0x5181 | 0x5182 | 0x5184
+        | 0x52c6 | 0x5306 | 0x5316 | 0x5346 | 0x5386 | 0x5394 | 0x54c5 | 0x5545 | 0x5585
+        | 0x5843 | 0x5853 | 0x58c3 | 0x58d1 | 0x5943 | 0x5983 | 0x6043 | 0x6045 | 0x6046
+        | 0x6082 | 0x6083 | 0x6085 | 0x6086 | 0x608a | 0x60c1 | 0x60c2 | 0x60c4 | 0x6103
+        | 0x6105 | 0x6106 | 0x6141 | 0x6142 | 0x6144 | 0x6181 | 0x6182 | 0x6184 | 0x6246
+        | 0x624e | 0x6286 | 0x628e | 0x62c6 | 0x62ca | 0x62cc | 0x6346 | 0x6386 | 0x64c5
+        | 0x6505 | 0x650d | 0x6545 | 0x654c | 0x6585 | 0x6883 | 0x688a | 0x688b | 0x68c3
+        | 0x68ca | 0x6903 | 0x690b | 0x6943 | 0x6983 | 0x6989 | 0x698a | 0x7043 | 0x7045
+        | 0x7046 | 0x7083 | 0x7085 | 0x7086 | 0x70c1 | 0x70c2 | 0x70c4 | 0x7103 | 0x7105
+        | 0x7106 | 0x7141 | 0x7142 | 0x7144 | 0x7181 | 0x7182 | 0x7184 | 0x72c6 | 0x7346
+        | 0x7386 | 0x74c5 | 0x7545 | 0x7585 | 0x78c3 | 0x7943 | 0x7983 | 0x8006 | 0x8009
+        | 0x800a | 0x8022 | 0x8024 | 0x8028 | 0x8082 | 0x80a0 | 0x8180 | 0x8206 | 0x820e
+        | 0x8216 | 0x8222 | 0x8226 | 0x822a | 0x8232 | 0x8234 | 0x8286 | 0x828a | 0x8294
+        | 0x82a0 | 0x82a2 | 0x82a4 | 0x82a8 | 0x82b0 | 0x8306 | 0x8312 | 0x8314 | 0x8322
+        | 0x8330 | 0x8380 | 0x8382 | 0x8384 | 0x8388 | 0x8390 | 0x83a0 | 0x840d | 0x8429
+        | 0x8509 | 0x8588 | 0x8590 | 0x85a0 | 0x86b4 | 0x8790 | 0x8794 | 0x8798 | 0x87a8
+        | 0x87b0 | 0x880b | 0x8823 | 0x8829 | 0x882a | 0x8883 | 0x8889 | 0x888a | 0x88a1
+        | 0x88a2 | 0x88a8 | 0x8903 | 0x8909 | 0x890a | 0x8921 | 0x8922 | 0x8928 | 0x8981
+        | 0x8982 | 0x8988 | 0x89a0 | 0x8aaa | 0x8b22 | 0x8b2a | 0x8b32 | 0x8b88 | 0x8b8a
+        | 0x8b98 | 0x8ba0 | 0x8ba2 | 0x8ba8 | 0x8bb0 | 0x8ca9 | 0x8d29 | 0x8d88 | 0x8d89
+        | 0x8d98 | 0x8da0 | 0x8da1 | 0x8da8 | 0x8db0 | 0x8f98 | 0x8fa8 | 0x8fb0 | 0x9016
+        | 0x9026 | 0x9032 | 0x9034 | 0x9086 | 0x9094 | 0x90a2 | 0x90a4 | 0x90b0 | 0x9106
+        | 0x9112 | 0x9114 | 0x9122 | 0x9130 | 0x9180 | 0x9181 | 0x9182 | 0x9184 | 0x9190
+        | 0x91a0 | 0x9216 | 0x9226 | 0x9232 | 0x9234 | 0x9286 | 0x9294 | 0x92a2 | 0x92a4
+        | 0x92b0 | 0x9306 | 0x9312 | 0x9314 | 0x9322 | 0x9330 | 0x9382 | 0x9384 | 0x9390
+        | 0x93a0 | 0x94b4 | 0x9594 | 0x95b0 | 0x96b4 | 0x9794 | 0x97b0 | 0x98a3 | 0x9922
+        | 0x9923 | 0x9932 | 0x9983 | 0x99a0 | 0x99a1 | 0x99a2 | 0x99b0 | 0x9b32 | 0x9ba2
+        | 0x9bb0 | 0xa00b | 0xa023 | 0xa028 | 0xa029 | 0xa02a | 0xa02c | 0xa082 | 0xa083
+        | 0xa086 | 0xa089 | 0xa08a | 0xa0a1 | 0xa0a2 | 0xa0a8 | 0xa103 | 0xa109 | 0xa10a
+        | 0xa121 | 0xa122 | 0xa128 | 0xa181 | 0xa182 | 0xa188 | 0xa1a0 | 0xa22a | 0xa22e
+        | 0xa286 | 0xa28a | 0xa28e | 0xa2a2 | 0xa2a6 | 0xa2a8 | 0xa2aa | 0xa2ac | 0xa32a
+        | 0xa382 | 0xa386 | 0xa38a | 0xa3a2 | 0xa3a8 | 0xa429 | 0xa42d | 0xa4a9 | 0xa509
+        | 0xa50d | 0xa529 | 0xa589 | 0xa5a1 | 0xa5a8 | 0xa80b | 0xa823 | 0xa829 | 0xa82a
+        | 0xa883 | 0xa889 | 0xa88a | 0xa8a1 | 0xa8a2 | 0xa8a8 | 0xa903 | 0xa909 | 0xa90a
+        | 0xa921 | 0xa922 | 0xa928 | 0xa981 | 0xa982 | 0xa988 | 0xa9a0 | 0xaaaa | 0xab2a
+        | 0xab8a | 0xaba2 | 0xaba8 | 0xaca9 | 0xad29 | 0xad89 | 0xada1 | 0xada8 | 0xb0a2
+        | 0xb0a3 | 0xb0a6 | 0xb123 | 0xb181 | 0xb182 | 0xb183 | 0xb185 | 0xb186 | 0xb1a1
+        | 0xb1a2 | 0xb2a6 | 0xb386 | 0xb3a2 | 0xb8a3 | 0xb923 | 0xb983 | 0xb9a1 | 0xb9a2
+        | 0xc00b | 0xc019 | 0xc089 | 0xc181 | 0xc182 | 0xc184 | 0xc316 | 0xc386 | 0xc394
+        | 0xc80b | 0xc81b | 0xc889 | 0xc88b | 0xc899 | 0xc90b | 0xc983 | 0xc989 | 0xc98a
+        | 0xd116 | 0xd183 | 0xd184 | 0xd185 | 0xd186 | 0xd194 | 0xd316 | 0xd386 | 0xd394
+        | 0xe08b | 0xe10b | 0xe181 | 0xe182 | 0xe183 | 0xe185 | 0xe186 | 0xe189 | 0xe18a
+        | 0xe88b | 0xe90b | 0xe983 | 0xe989 | 0xe98a | 0xf183 | 0xf185 | 0xf186 | 0x10009
+        | 0x10024 | 0x10028 | 0x1020e | 0x10216 | 0x10226 | 0x1024c | 0x10268 | 0x10348
+        | 0x1040d | 0x10425 | 0x10429 | 0x1042c | 0x10445 | 0x1044c | 0x10461 | 0x10464
+        | 0x10468 | 0x10505 | 0x10509 | 0x1050c | 0x10521 | 0x10528 | 0x10541 | 0x10544
+        | 0x10548 | 0x10560 | 0x1066c | 0x10748 | 0x1074c | 0x10758 | 0x10768 | 0x10770
+        | 0x1080b | 0x10813 | 0x10823 | 0x10921 | 0x10928 | 0x10960 | 0x10b58 | 0x10b68
+        | 0x10b70 | 0x10d29 | 0x10d58 | 0x10d60 | 0x10d61 | 0x10d68 | 0x10d70 | 0x10f58
+        | 0x10f68 | 0x10f70 | 0x11032 | 0x11141 | 0x11236 | 0x11316 | 0x11332 | 0x11465
+        | 0x11545 | 0x11561 | 0x1200d | 0x12025 | 0x12028 | 0x12029 | 0x1202a | 0x1202c
+        | 0x12045 | 0x1204c | 0x12061 | 0x12064 | 0x12068 | 0x12105 | 0x12109 | 0x1210c
+        | 0x12121 | 0x12128 | 0x12141 | 0x12144 | 0x12148 | 0x12160 | 0x1222e | 0x1224c
+        | 0x1224e | 0x12266 | 0x12268 | 0x1226a | 0x1226c | 0x1234c | 0x12368 | 0x1240d
+        | 0x12425 | 0x12429 | 0x1242c | 0x12445 | 0x1244c | 0x12461 | 0x12464 | 0x12468
+        | 0x12505 | 0x12509 | 0x1250c | 0x12521 | 0x12528 | 0x12541 | 0x12544 | 0x12548
+        | 0x12560 | 0x1266c | 0x1274c | 0x12768 | 0x1282b | 0x1290b | 0x12921 | 0x12923
+        | 0x12928 | 0x12929 | 0x1292a | 0x12961 | 0x12968 | 0x12d29 | 0x12d61 | 0x12d68
+        | 0x13065 | 0x13141 | 0x13143 | 0x13145 | 0x13146 | 0x13161 | 0x13465 | 0x13545
+        | 0x13561 | 0x1401a | 0x14144 | 0x1450d | 0x14545 | 0x1454c | 0x1481b | 0x14853
+        | 0x1485a | 0x15143 | 0x15145 | 0x15146 | 0x1610d | 0x16143 | 0x16144 | 0x16145
+        | 0x16146 | 0x1614c | 0x1650d | 0x16545 | 0x1654c | 0x17143 | 0x17145 | 0x17146
+        | 0x18009 | 0x1800b | 0x1800d | 0x1800e | 0x18013 | 0x18015 | 0x18016 | 0x18019
+        | 0x1801a | 0x1801c | 0x18023 | 0x18025 | 0x18026 | 0x18029 | 0x1802a | 0x1802c
+        | 0x18031 | 0x18032 | 0x18034 | 0x18109 | 0x18216 | 0x1821e | 0x1822e | 0x18236
+        | 0x18316 | 0x18332 | 0x1840d | 0x1841d | 0x1842d | 0x18435 | 0x1850d | 0x18529
+        | 0x1880b | 0x1881b | 0x18823 | 0x1882b | 0x18833 | 0x1890b | 0x18923 | 0x18929
+        | 0x1892a | 0x19032 | 0x19033 | 0x19035 | 0x19036 | 0x19116 | 0x19132 | 0x19236
+        | 0x19316 | 0x19332 | 0x1a029 | 0x1a02a | 0x1a02b | 0x1a02d | 0x1a02e | 0x1a109
+        | 0x1a10b | 0x1a10d | 0x1a123 | 0x1a129 | 0x1a12a | 0x1a42d | 0x1a50d | 0x1a529
+        | 0x1a82b | 0x1a90b | 0x1a923 | 0x1a929 | 0x1a92a | 0x1c01b | 0x1c01d | 0x1c01e
+        | 0x20003 | 0x20009 | 0x2000a | 0x20022 | 0x20024 | 0x20028 | 0x20082 | 0x20088
+        | 0x200c0 | 0x2020e | 0x20226 | 0x2022a | 0x2022c | 0x20246 | 0x2024a | 0x2024c
+        | 0x20262 | 0x20264 | 0x20268 | 0x20286 | 0x2028a | 0x2028c | 0x202a2 | 0x202a4
+        | 0x202a8 | 0x202c2 | 0x202c4 | 0x202c8 | 0x202e0 | 0x20425 | 0x2042c | 0x20464
+        | 0x204c8 | 0x204d0 | 0x204e0 | 0x2066c | 0x206ac | 0x206c8 | 0x206cc | 0x206d8
+        | 0x206e0 | 0x206e4 | 0x206e8 | 0x206f0 | 0x20803 | 0x2080a | 0x2080b | 0x20813
+        | 0x20819 | 0x2081a | 0x20823 | 0x2082a | 0x20843 | 0x2084a | 0x20851 | 0x20852
+        | 0x20858 | 0x20883 | 0x20888 | 0x20889 | 0x2088a | 0x20891 | 0x20898 | 0x208a2
+        | 0x208a8 | 0x208c0 | 0x208c1 | 0x208c2 | 0x208c8 | 0x208d0 | 0x208e0 | 0x20a4a
+        | 0x20a5a | 0x20a6a | 0x20aaa | 0x20ac8 | 0x20aca | 0x20ad8 | 0x20ae0 | 0x20ae2
+        | 0x20ae8 | 0x20af0 | 0x20c99 | 0x20cd0 | 0x20cd1 | 0x20cd8 | 0x20ce8 | 0x20cf0
+        | 0x20ed8 | 0x20ee8 | 0x20ef0 | 0x21026 | 0x21034 | 0x210a4 | 0x210c1 | 0x210c2
+        | 0x210c4 | 0x21226 | 0x21236 | 0x21266 | 0x212a4 | 0x212a6 | 0x212b4 | 0x212c6
+        | 0x212e2 | 0x212e4 | 0x21853 | 0x218c3 | 0x218d1 | 0x2200e | 0x22026 | 0x22028
+        | 0x22029 | 0x2202a | 0x2202c | 0x22046 | 0x2204a | 0x2204c | 0x22062 | 0x22064
+        | 0x22068 | 0x22082 | 0x22083 | 0x22086 | 0x2208a | 0x2208c | 0x220a2 | 0x220a4
+        | 0x220a8 | 0x220c2 | 0x220c4 | 0x220c8 | 0x220e0 | 0x2220e | 0x22226 | 0x2222a
+        | 0x2222c | 0x22246 | 0x2224a | 0x2224c | 0x22262 | 0x22264 | 0x22268 | 0x22286
+        | 0x2228a | 0x2228c | 0x222a2 | 0x222a4 | 0x222a8 | 0x222c2 | 0x222c4 | 0x222c8
+        | 0x222e0 | 0x2242c | 0x2242d | 0x22464 | 0x22465 | 0x2246c | 0x224ac | 0x224cc
+        | 0x224e4 | 0x224e8 | 0x2266c | 0x226ac | 0x226cc | 0x226e4 | 0x226e8 | 0x2282a
+        | 0x2282b | 0x2286a | 0x22883 | 0x2288a | 0x2288b | 0x228a2 | 0x228a3 | 0x228a8
+        | 0x228a9 | 0x228aa | 0x228c2 | 0x228c3 | 0x228ca | 0x228e2 | 0x228e8 | 0x22a6a
+        | 0x22aaa | 0x22aca | 0x22ae2 | 0x22ae8 | 0x23066 | 0x230a6 | 0x230c2 | 0x230c3
+        | 0x230c4 | 0x230c5 | 0x230c6 | 0x230e2 | 0x230e4 | 0x23266 | 0x232a6 | 0x232c6
+        | 0x232e2 | 0x232e4 | 0x2400b | 0x24013 | 0x24019 | 0x2401a | 0x24043 | 0x2404a
+        | 0x24051 | 0x24052 | 0x24058 | 0x24083 | 0x24089 | 0x2408a | 0x24091 | 0x24098
+        | 0x240c0 | 0x240c1 | 0x240c2 | 0x240c4 | 0x240c8 | 0x240d0 | 0x2424a | 0x2424e
+        | 0x2425a | 0x2428e | 0x242c6 | 0x242c8 | 0x242ca | 0x242cc | 0x242d8 | 0x24499
+        | 0x244d1 | 0x244d8 | 0x2480b | 0x24813 | 0x24819 | 0x2481a | 0x24843 | 0x2484a
+        | 0x24851 | 0x24852 | 0x24858 | 0x24883 | 0x24889 | 0x2488a | 0x24891 | 0x24898
+        | 0x248c1 | 0x248c2 | 0x248c8 | 0x248d0 | 0x24a5a | 0x24aca | 0x24ad8 | 0x24c99
+        | 0x24cd1 | 0x24cd8 | 0x25053 | 0x250c1 | 0x250c3 | 0x250c5 | 0x250c6 | 0x250d1
+        | 0x25853 | 0x258c3 | 0x258d1 | 0x2604e | 0x2608a | 0x2608b | 0x2608e | 0x260c2
+        | 0x260c3 | 0x260c4 | 0x260c5 | 0x260c6 | 0x260ca | 0x260cc | 0x2624e | 0x2628e
+        | 0x262c6 | 0x262ca | 0x262cc | 0x2688b | 0x268c3 | 0x268ca | 0x270c3 | 0x270c5
+        | 0x270c6 | 0x28009 | 0x2800a | 0x2800b | 0x2800d | 0x2800e | 0x28013 | 0x28015
+        | 0x28016 | 0x28019 | 0x2801a | 0x2801c | 0x28022 | 0x28023 | 0x28024 | 0x28025
+        | 0x28026 | 0x28029 | 0x2802a | 0x2802c | 0x28031 | 0x28032 | 0x28034 | 0x28089
+        | 0x2808a | 0x280a2 | 0x280a4 | 0x280a8 | 0x2820e | 0x2821e | 0x28226 | 0x2822e
+        | 0x28236 | 0x2828e | 0x282a4 | 0x282a6 | 0x282aa | 0x282ac | 0x282b4 | 0x2841d
+        | 0x2842d | 0x28435 | 0x2880b | 0x2881b | 0x28823 | 0x2882b | 0x28833 | 0x28889
+        | 0x2888b | 0x28899 | 0x288a3 | 0x288a9 | 0x288aa | 0x29033 | 0x29034 | 0x29035
+        | 0x29036 | 0x290a6 | 0x290b4 | 0x29236 | 0x292a6 | 0x292b4 | 0x2a029 | 0x2a02a
+        | 0x2a02b | 0x2a02c | 0x2a02d | 0x2a02e | 0x2a08a | 0x2a08b | 0x2a08e | 0x2a0a2
+        | 0x2a0a3 | 0x2a0a6 | 0x2a0a8 | 0x2a0a9 | 0x2a0aa | 0x2a0ac | 0x2a22e | 0x2a28e
+        | 0x2a2a6 | 0x2a2aa | 0x2a2ac | 0x2a82b | 0x2a88b | 0x2a8a3 | 0x2a8a9 | 0x2a8aa
+        | 0x2c019 | 0x2c01b | 0x2c01d | 0x2c01e | 0x2c08b | 0x2c099 | 0x2c81b | 0x2c88b
+        | 0x2c899 | 0x3000b | 0x3000d | 0x3000e | 0x30013 | 0x30015 | 0x30016 | 0x30019
+        | 0x3001a | 0x3001c | 0x30023 | 0x30024 | 0x30025 | 0x30026 | 0x30029 | 0x3002a
+        | 0x3002c | 0x30031 | 0x30032 | 0x30034 | 0x30064 | 0x3020e | 0x3021e | 0x30226
+        | 0x3022e | 0x30236 | 0x3024e | 0x30266 | 0x3026a | 0x3026c | 0x3041d | 0x30425
+        | 0x3042d | 0x30435 | 0x30465 | 0x3046c | 0x30813 | 0x3081b | 0x3082b | 0x30833
+        | 0x30853 | 0x3085a | 0x31033 | 0x31035 | 0x31036 | 0x3202a | 0x3202b | 0x3202c
+        | 0x3202d | 0x3202e | 0x3204e | 0x32064 | 0x32065 | 0x32066 | 0x3206a | 0x3206c
+        | 0x3222e | 0x3224e | 0x32266 | 0x3226a | 0x3226c | 0x3242d | 0x32465 | 0x3246c
+        | 0x3401a | 0x3401b | 0x3401d | 0x3401e | 0x34053 | 0x3405a | 0x3481b | 0x34853
+        | 0x3485a | 0x3800b | 0x3800d | 0x3800e | 0x38013 | 0x38015 | 0x38016 | 0x38019
+        | 0x3801a | 0x3801c | 0x38023 | 0x38025 | 0x38026 | 0x38029 | 0x3802a | 0x3802c
+        | 0x38031 | 0x38032 | 0x38034 | 0x3821e | 0x3822e | 0x38236 | 0x3841d | 0x3842d
+        | 0x38435 | 0x3881b | 0x3882b | 0x38833 | 0x39033 | 0x39035 | 0x39036 | 0x3a02b
+        | 0x3a02d | 0x3a02e | 0x3c01b | 0x3c01d | 0x3c01e => Some(-1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(notation: &str, x_to_move: bool) -> Position {
+        Position {
+            board: notation.parse().unwrap(),
+            x_to_move,
+        }
+    }
+
+    #[test]
+    fn evaluates_games_either_side_started() {
+        assert_eq!(evaluate(Position::START), 0);
+        // X to move completes the a1–c1 row
+        assert_eq!(evaluate(position("---/OO-/XX-", true)), 1);
+        // The same marks in a game O started, with O to move and winning
+        assert_eq!(evaluate(position("---/XX-/OO-", false)), -1);
+        assert_eq!(
+            lookup(position("---/XX-/OO-", false)),
+            lookup(position("---/OO-/XX-", true)).map(|evaluation| -evaluation)
+        );
+    }
+
+    #[test]
+    fn looks_up_only_positions_from_a_game() {
+        assert_eq!(lookup(position("---/---/XX-", true)), None);
+        assert_eq!(lookup(position("OOO/---/XXX", true)), None);
+        assert_eq!(lookup(position("---/---/X--", false)), Some(0));
+        assert_eq!(lookup(position("---/---/O--", true)), Some(0));
+    }
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(distance(Position::START), 9);
+        assert_eq!(distance(position("---/OO-/XX-", true)), 1);
+        // X threatens a2, c1 and c3, so O blocks one and loses on the next ply
+        assert_eq!(distance(position("X--/-XO/XO-", false)), 2);
+        assert_eq!(distance(position("XXX/OO-/---", false)), 0);
+    }
+
+    #[test]
+    fn finds_the_outcome_of_each_square() {
+        let outcomes = outcomes(position("---/OO-/XX-", true));
+        let win = Outcome {
+            result: 1,
+            distance: 1,
+        };
+        assert_eq!(outcomes[2], Some(win));
+        assert_eq!(outcomes[0], None);
+        // Anything else lets O finish the middle row straight away
+        assert_eq!(
+            outcomes[8],
+            Some(Outcome {
+                result: -1,
+                distance: 2
+            })
+        );
+        assert_eq!(
+            best_moves(position("---/OO-/XX-", true)),
+            BitBoard::new(1 << 2)
+        );
+    }
+
+    #[test]
+    fn takes_the_fastest_win_and_the_slowest_loss() {
+        // After a1 b1 b2 c1, c3 wins at once where a2, c2 and a3 win later
+        let winning = position("---/-X-/XOO", true);
+        assert_eq!(best_moves(winning), BitBoard::new(0b101_101_000));
+        assert_eq!(perfect_moves(winning), BitBoard::new(1 << 8));
+        // After a1 b1 b2 every move loses, but only c3 holds out past X's next move
+        let losing = position("---/-X-/XO-", false);
+        assert_eq!(best_moves(losing), losing.moves());
+        assert_eq!(perfect_moves(losing), BitBoard::new(1 << 8));
+    }
+}
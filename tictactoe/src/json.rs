@@ -0,0 +1,347 @@
+use core::fmt::Display;
+use core::fmt::Formatter;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A JSON value. Objects keep their keys in order
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Self>),
+    Object(Vec<(String, Self)>),
+}
+
+impl Value {
+    /// Builds an object from its keys and values
+    pub fn object<const N: usize>(fields: [(&str, Self); N]) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    /// A field of an object, if this is one and has it
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Object(fields) => fields
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Parses one JSON value, with nothing but whitespace around it
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars().peekable();
+        let value = parse_value(&mut chars, 0)?;
+        skip_whitespace(&mut chars);
+        chars.peek().is_none().then_some(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(string: &str) -> Self {
+        Self::String(string.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(string: String) -> Self {
+        Self::String(string)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(bool: bool) -> Self {
+        Self::Bool(bool)
+    }
+}
+
+impl From<u8> for Value {
+    fn from(number: u8) -> Self {
+        Self::Number(f64::from(number))
+    }
+}
+
+impl<T: Into<Self>> From<Option<T>> for Value {
+    fn from(option: Option<T>) -> Self {
+        option.map_or(Self::Null, Into::into)
+    }
+}
+
+impl<T: Into<Self>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Self::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, string: &str) -> core::fmt::Result {
+    write!(f, "\"")?;
+    for character in string.chars() {
+        match character {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            character if character.is_control() => write!(f, "\\u{:04x}", u32::from(character))?,
+            character => write!(f, "{character}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Compact JSON on one line
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Bool(bool) => write!(f, "{bool}"),
+            Self::Number(number) => write!(f, "{number}"),
+            Self::String(string) => write_string(f, string),
+            Self::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Self::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index != 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(char::is_ascii_whitespace).is_some() {}
+}
+
+/// Consumes `word` after its first character was peeked
+fn expect(chars: &mut Peekable<Chars>, word: &str) -> Option<()> {
+    word.chars()
+        .all(|character| chars.next() == Some(character))
+        .then_some(())
+}
+
+/// Arrays and objects nested deeper than this are rejected rather than overflowing the stack
+const MAX_DEPTH: usize = 64;
+
+/// Parses a value nested inside `depth` arrays and objects
+fn parse_value(chars: &mut Peekable<Chars>, depth: usize) -> Option<Value> {
+    skip_whitespace(chars);
+    let nested = matches!(chars.peek(), Some('[' | '{'));
+    if nested && depth == MAX_DEPTH {
+        return None;
+    }
+    Some(match *chars.peek()? {
+        'n' => expect(chars, "null").map(|()| Value::Null)?,
+        't' => expect(chars, "true").map(|()| Value::Bool(true))?,
+        'f' => expect(chars, "false").map(|()| Value::Bool(false))?,
+        '"' => Value::String(parse_string(chars)?),
+        '[' => {
+            chars.next();
+            let mut values = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_none() {
+                loop {
+                    values.push(parse_value(chars, depth + 1)?);
+                    skip_whitespace(chars);
+                    match chars.next()? {
+                        ',' => {}
+                        ']' => break,
+                        _ => return None,
+                    }
+                }
+            }
+            Value::Array(values)
+        }
+        '{' => {
+            chars.next();
+            let mut fields = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_none() {
+                loop {
+                    skip_whitespace(chars);
+                    let key = parse_string(chars)?;
+                    skip_whitespace(chars);
+                    chars.next_if_eq(&':')?;
+                    fields.push((key, parse_value(chars, depth + 1)?));
+                    skip_whitespace(chars);
+                    match chars.next()? {
+                        ',' => {}
+                        '}' => break,
+                        _ => return None,
+                    }
+                }
+            }
+            Value::Object(fields)
+        }
+        '-' | '0'..='9' => {
+            let mut number = String::new();
+            while let Some(character) = chars.next_if(|character| {
+                character.is_ascii_digit() || matches!(character, '-' | '+' | '.' | 'e' | 'E')
+            }) {
+                number.push(character);
+            }
+            // Numbers too big for an f64 would come back out as inf, which isn't JSON
+            Value::Number(
+                number
+                    .parse()
+                    .ok()
+                    .filter(|number: &f64| number.is_finite())?,
+            )
+        }
+        _ => return None,
+    })
+}
+
+/// Four hex digits of a `\u` escape
+fn parse_hex(chars: &mut Peekable<Chars>) -> Option<u32> {
+    (0..4).try_fold(0, |code, _| Some(code * 16 + chars.next()?.to_digit(16)?))
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    chars.next_if_eq(&'"')?;
+    let mut string = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(string),
+            '\\' => string.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    let mut code = parse_hex(chars)?;
+                    // A character outside the basic plane comes as a surrogate pair
+                    if (0xd800..0xdc00).contains(&code) {
+                        expect(chars, "\\u")?;
+                        let low = parse_hex(chars)?;
+                        if !(0xdc00..0xe000).contains(&low) {
+                            return None;
+                        }
+                        code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                    }
+                    char::from_u32(code)?
+                }
+                _ => return None,
+            }),
+            character if character.is_control() => return None,
+            character => string.push(character),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_values() {
+        assert_eq!(Value::parse(" null "), Some(Value::Null));
+        assert_eq!(Value::parse("true"), Some(Value::Bool(true)));
+        assert_eq!(Value::parse("false"), Some(Value::Bool(false)));
+        assert_eq!(Value::parse("-1.5e2"), Some(Value::Number(-150.0)));
+        assert_eq!(Value::parse("0"), Some(Value::Number(0.0)));
+        assert_eq!(
+            Value::parse(r#"{"a": [1, "b", {}], "c": []}"#),
+            Some(Value::object([
+                (
+                    "a",
+                    Value::Array(vec![Value::Number(1.0), "b".into(), Value::Object(vec![])])
+                ),
+                ("c", Value::Array(vec![])),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parses_escapes() {
+        assert_eq!(
+            Value::parse(r#""\"\\\/\b\f\n\r\t""#),
+            Some("\"\\/\u{8}\u{c}\n\r\t".into())
+        );
+        assert_eq!(Value::parse(r#""\u00e9\u20AC""#), Some("é€".into()));
+        assert_eq!(Value::parse(r#""\ud83d\ude00""#), Some("😀".into()));
+        // A high surrogate needs a low one after it, and a lone low one isn't a character
+        assert_eq!(Value::parse(r#""\ud83d""#), None);
+        assert_eq!(Value::parse(r#""\ud83dA""#), None);
+        assert_eq!(Value::parse(r#""\ude00""#), None);
+        assert_eq!(Value::parse(r#""\x""#), None);
+        assert_eq!(Value::parse("\"\n\""), None);
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        for text in [
+            "",
+            "nul",
+            "[1,]",
+            "[1 2]",
+            "{\"a\"}",
+            "{\"a\":1,}",
+            "{true:1}",
+            "\"open",
+            "1e400",
+            "-1e400",
+            "1 2",
+            "{} x",
+            "[]]",
+        ] {
+            assert_eq!(Value::parse(text), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn limits_nesting() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(Value::parse(&nested(MAX_DEPTH)).is_some());
+        assert_eq!(Value::parse(&nested(MAX_DEPTH + 1)), None);
+        assert_eq!(Value::parse(&"[".repeat(200_000)), None);
+        assert_eq!(Value::parse(&"{\"a\":".repeat(200_000)), None);
+    }
+
+    #[test]
+    fn writes_compact_json() {
+        let value = Value::object([
+            ("text", "a \"b\"\n\u{1}".into()),
+            ("list", vec![Some(true), None].into()),
+            ("number", 8u8.into()),
+        ]);
+        let text = value.to_string();
+        assert_eq!(
+            text,
+            r#"{"text":"a \"b\"\n\u0001","list":[true,null],"number":8}"#
+        );
+        assert_eq!(Value::parse(&text), Some(value));
+    }
+}
@@ -2,4 +2,13 @@
 #![warn(clippy::pedantic)]
 #![warn(clippy::nursery)]
 
+pub mod agent;
 pub mod board;
+pub mod controller;
+pub mod evaluation;
+pub mod mcts;
+mod perft;
+pub mod position;
+pub mod random;
+
+pub use perft::{perft, perft_divide};
@@ -0,0 +1,391 @@
+use std::io::{self, BufRead, Write};
+use tictactoe::board::bit_board::BitBoard;
+use tictactoe::board::scheme::Scheme;
+use tictactoe::board::{parse_square, square_name};
+use tictactoe::controller::{GameController, GameEvent, MoveError};
+use tictactoe::evaluation::{distance, evaluate, perfect_moves};
+use tictactoe::random::Random;
+
+use crate::json::Value;
+
+const fn side(x: bool) -> &'static str {
+    if x {
+        "x"
+    } else {
+        "o"
+    }
+}
+
+fn error<const N: usize>(kind: &str, fields: [(&str, Value); N]) -> Value {
+    let mut error = vec![(String::from("error"), Value::from(kind))];
+    error.extend(fields.map(|(key, value)| (key.to_owned(), value)));
+    Value::Object(error)
+}
+
+/// A string field of the request, `None` if it's absent, or the error for any other type
+fn string_field<'a>(request: &'a Value, field: &str) -> Result<Option<&'a str>, Value> {
+    let Some(value) = request.get(field) else {
+        return Ok(None);
+    };
+    value.as_str().map(Some).ok_or_else(|| {
+        error(
+            "invalid_type",
+            [("field", field.into()), ("expected", "string".into())],
+        )
+    })
+}
+
+fn event(event: GameEvent) -> Value {
+    match event {
+        GameEvent::MovePlayed { x, square } => Value::object([
+            ("event", "move".into()),
+            ("side", side(x).into()),
+            ("square", square_name(square).into()),
+        ]),
+        GameEvent::Threat { x, squares } => Value::object([
+            ("event", "threat".into()),
+            ("side", side(x).into()),
+            ("squares", Scheme::Algebraic.names(squares).into()),
+        ]),
+        GameEvent::GameOver { winner } => Value::object([
+            ("event", "game_over".into()),
+            ("winner", winner.map(side).into()),
+        ]),
+        GameEvent::Evaluation { winner, distance } => Value::object([
+            ("event", "evaluation".into()),
+            ("winner", winner.map(side).into()),
+            ("plies", distance.into()),
+        ]),
+        GameEvent::MovesUndone { count } => {
+            Value::object([("event", "undo".into()), ("count", count.into())])
+        }
+        GameEvent::BoardReset { x_moves_first } => Value::object([
+            ("event", "reset".into()),
+            ("first", side(x_moves_first).into()),
+        ]),
+    }
+}
+
+/// The game as it stands, with the events since the last response
+fn state(controller: &mut GameController) -> Value {
+    let position = controller.position();
+    let history: Vec<String> = controller
+        .history()
+        .iter()
+        .map(|index| square_name(*index))
+        .collect();
+    let events: Vec<Value> = controller.events().map(event).collect();
+    Value::object([
+        ("board", position.board.notation().into()),
+        ("to_move", side(position.x_to_move).into()),
+        ("history", history.into()),
+        ("over", controller.is_over().into()),
+        ("winner", controller.winner().map(side).into()),
+        ("events", Value::Array(events)),
+    ])
+}
+
+/// The response to one request
+fn respond(controller: &mut GameController, request: &Value) -> Value {
+    let op = match string_field(request, "op") {
+        Ok(Some(op)) => op,
+        Ok(None) => return error("missing", [("field", "op".into())]),
+        Err(error) => return error,
+    };
+    let position = controller.position();
+    match op {
+        "new" => {
+            let x_moves_first = match string_field(request, "first") {
+                Ok(None | Some("x")) => true,
+                Ok(Some("o")) => false,
+                Ok(Some(first)) => return error("invalid_side", [("first", first.into())]),
+                Err(error) => return error,
+            };
+            controller.new_game(x_moves_first);
+            state(controller)
+        }
+        "play" => {
+            let name = match string_field(request, "square") {
+                Ok(Some(name)) => name,
+                Ok(None) => return error("missing", [("field", "square".into())]),
+                Err(error) => return error,
+            };
+            let Some(index) = parse_square(&name.to_ascii_lowercase()) else {
+                return error("invalid_square", [("square", name.into())]);
+            };
+            match controller.play(index) {
+                Ok(()) => state(controller),
+                Err(MoveError::Occupied) => error("occupied", [("square", name.into())]),
+                Err(MoveError::GameOver) => error("game_over", []),
+                Err(MoveError::OutOfRange) => error("invalid_square", [("square", name.into())]),
+            }
+        }
+        "legal" => {
+            let moves = if controller.is_over() {
+                BitBoard::EMPTY
+            } else {
+                position.moves()
+            };
+            Value::object([("moves", Scheme::Algebraic.names(moves).into())])
+        }
+        "evaluate" => {
+            let winner = if controller.is_over() {
+                controller.winner()
+            } else {
+                match evaluate(position) {
+                    1 => Some(true),
+                    -1 => Some(false),
+                    _ => None,
+                }
+            };
+            let result = match winner {
+                Some(x) if x == position.x_to_move => "win",
+                Some(_) => "loss",
+                None => "draw",
+            };
+            let plies = if controller.is_over() {
+                0
+            } else {
+                distance(position)
+            };
+            Value::object([
+                ("result", result.into()),
+                ("winner", winner.map(side).into()),
+                ("plies", plies.into()),
+            ])
+        }
+        "best" => {
+            if controller.is_over() {
+                return error("game_over", []);
+            }
+            let moves = Scheme::Algebraic.names(perfect_moves(position));
+            Value::object([
+                ("square", moves.first().cloned().into()),
+                ("moves", moves.into()),
+            ])
+        }
+        "undo" => {
+            if controller.undo() {
+                state(controller)
+            } else {
+                error("nothing_to_undo", [])
+            }
+        }
+        "render" => Value::object([("text", position.board.to_string().into())]),
+        _ => error("unknown_op", [("op", op.into())]),
+    }
+}
+
+/// Answers one JSON request per line of `input` with one JSON response per line of `output`,
+/// until the end of the input. Both sides are played by the requests
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut controller = GameController::new(None, None, true, Random::new(0));
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match Value::parse(&line) {
+            Some(request @ Value::Object(_)) => {
+                let mut response = respond(&mut controller, &request);
+                // An id in the request comes back, to match responses to requests
+                if let (Some(id), Value::Object(fields)) = (request.get("id"), &mut response) {
+                    fields.insert(0, (String::from("id"), id.clone()));
+                }
+                response
+            }
+            _ => error("invalid_json", []),
+        };
+        writeln!(output, "{response}")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tictactoe::board::Board;
+
+    /// Responses to each request, in order, from one session
+    fn session(requests: &[&str]) -> Vec<Value> {
+        let mut output = Vec::new();
+        run(requests.join("\n").as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| Value::parse(line).unwrap())
+            .collect()
+    }
+
+    fn one(request: &str) -> Value {
+        session(&[request]).remove(0)
+    }
+
+    fn field<'a>(value: &'a Value, key: &str) -> &'a Value {
+        value
+            .get(key)
+            .unwrap_or_else(|| panic!("no {key} in {value}"))
+    }
+
+    #[test]
+    fn plays_and_reports_state() {
+        let responses = session(&[
+            r#"{"op":"play","square":"B2","id":"first"}"#,
+            r#"{"op":"play","square":"a1"}"#,
+        ]);
+        assert_eq!(field(&responses[0], "id"), &"first".into());
+        assert_eq!(field(&responses[1], "board"), &"---/-X-/O--".into());
+        assert_eq!(field(&responses[1], "to_move"), &"x".into());
+        assert_eq!(field(&responses[1], "history"), &vec!["b2", "a1"].into());
+        assert_eq!(field(&responses[1], "over"), &false.into());
+        let events = field(&responses[1], "events");
+        assert_eq!(
+            events.to_string(),
+            r#"[{"event":"move","side":"o","square":"a1"},{"event":"evaluation","winner":null,"plies":7}]"#
+        );
+    }
+
+    #[test]
+    fn reports_threats_and_the_end() {
+        let responses = session(&[
+            r#"{"op":"play","square":"a1"}"#,
+            r#"{"op":"play","square":"a2"}"#,
+            r#"{"op":"play","square":"b1"}"#,
+            r#"{"op":"play","square":"b2"}"#,
+            r#"{"op":"play","square":"c1"}"#,
+            r#"{"op":"play","square":"c2"}"#,
+            r#"{"op":"legal"}"#,
+            r#"{"op":"best"}"#,
+        ]);
+        assert!(responses[2]
+            .to_string()
+            .contains(r#"{"event":"threat","side":"x","squares":["c1"]}"#));
+        assert_eq!(field(&responses[4], "winner"), &"x".into());
+        assert!(responses[4]
+            .to_string()
+            .contains(r#"{"event":"game_over","winner":"x"}"#));
+        assert_eq!(responses[5], error("game_over", []));
+        assert_eq!(field(&responses[6], "moves"), &Value::Array(vec![]));
+        assert_eq!(responses[7], error("game_over", []));
+    }
+
+    #[test]
+    fn evaluates_and_suggests() {
+        let responses = session(&[
+            r#"{"op":"evaluate"}"#,
+            r#"{"op":"play","square":"b2"}"#,
+            r#"{"op":"play","square":"b1"}"#,
+            r#"{"op":"evaluate"}"#,
+            r#"{"op":"best"}"#,
+            r#"{"op":"legal"}"#,
+        ]);
+        assert_eq!(
+            responses[0],
+            Value::object([
+                ("result", "draw".into()),
+                ("winner", Value::Null),
+                ("plies", 9u8.into()),
+            ])
+        );
+        assert_eq!(field(&responses[3], "result"), &"win".into());
+        assert_eq!(field(&responses[3], "winner"), &"x".into());
+        let Value::Array(best) = field(&responses[4], "moves") else {
+            panic!("moves should be an array");
+        };
+        assert!(!best.is_empty());
+        assert_eq!(field(&responses[4], "square"), &best[0]);
+        assert_eq!(
+            field(&responses[5], "moves"),
+            &vec!["a1", "c1", "a2", "c2", "a3", "b3", "c3"].into()
+        );
+    }
+
+    #[test]
+    fn suggests_the_fastest_win() {
+        let responses = session(&[
+            r#"{"op":"play","square":"a1"}"#,
+            r#"{"op":"play","square":"b1"}"#,
+            r#"{"op":"play","square":"b2"}"#,
+            r#"{"op":"play","square":"c1"}"#,
+            r#"{"op":"best"}"#,
+        ]);
+        // a2, c2 and a3 would still win, but later
+        assert_eq!(
+            responses[4],
+            Value::object([("square", "c3".into()), ("moves", vec!["c3"].into())])
+        );
+    }
+
+    #[test]
+    fn undoes_and_starts_again() {
+        let responses = session(&[
+            r#"{"op":"undo"}"#,
+            r#"{"op":"play","square":"b2"}"#,
+            r#"{"op":"undo"}"#,
+            r#"{"op":"new","first":"o"}"#,
+            r#"{"op":"render"}"#,
+        ]);
+        assert_eq!(responses[0], error("nothing_to_undo", []));
+        assert_eq!(field(&responses[2], "history"), &Value::Array(vec![]));
+        assert!(responses[2]
+            .to_string()
+            .contains(r#"{"event":"undo","count":1}"#));
+        assert_eq!(field(&responses[3], "to_move"), &"o".into());
+        assert!(responses[3]
+            .to_string()
+            .contains(r#"{"event":"reset","first":"o"}"#));
+        assert_eq!(
+            field(&responses[4], "text"),
+            &Board::EMPTY.to_string().into()
+        );
+    }
+
+    #[test]
+    fn reports_errors() {
+        let responses = session(&[
+            r#"{"op":"play","square":"b2"}"#,
+            r#"{"op":"play","square":"b2"}"#,
+        ]);
+        assert_eq!(responses[1], error("occupied", [("square", "b2".into())]));
+
+        assert_eq!(
+            one(r#"{"op":"play","square":"d4"}"#),
+            error("invalid_square", [("square", "d4".into())])
+        );
+        assert_eq!(
+            one(r#"{"op":"play"}"#),
+            error("missing", [("field", "square".into())])
+        );
+        assert_eq!(
+            one(r#"{"op":"play","square":5}"#),
+            error(
+                "invalid_type",
+                [("field", "square".into()), ("expected", "string".into())]
+            )
+        );
+        assert_eq!(one("{}"), error("missing", [("field", "op".into())]));
+        assert_eq!(
+            one(r#"{"op":["play"]}"#),
+            error(
+                "invalid_type",
+                [("field", "op".into()), ("expected", "string".into())]
+            )
+        );
+        assert_eq!(
+            one(r#"{"op":"fly","id":3}"#),
+            Value::Object(vec![
+                (String::from("id"), 3u8.into()),
+                (String::from("error"), "unknown_op".into()),
+                (String::from("op"), "fly".into()),
+            ])
+        );
+        assert_eq!(
+            one(r#"{"op":"new","first":"z"}"#),
+            error("invalid_side", [("first", "z".into())])
+        );
+        for line in ["[]", "{", r#"{"id":1e400}"#] {
+            assert_eq!(one(line), error("invalid_json", []));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Instant;
+
+use crate::board::bit_board::BitBoard;
+use crate::position::Position;
+use crate::random::Random;
+
+struct Node {
+    position: Position,
+    parent: Option<usize>,
+    /// Square played to reach this node
+    index: u8,
+    children: Vec<usize>,
+    untried: BitBoard,
+    visits: u32,
+    /// Wins for the side that moved into this node, with draws counting half
+    reward: f64,
+}
+
+/// Finishes the game with random moves, returning the winner
+fn playout(mut position: Position, random: &mut Random) -> Option<bool> {
+    loop {
+        if let Some(winner) = position.winner() {
+            return Some(winner);
+        }
+        let moves = position.moves();
+        if moves == BitBoard::EMPTY {
+            return None;
+        }
+        position = position.play(random.pick(moves));
+    }
+}
+
+/// Monte Carlo tree search with UCT: the move visited most after `iterations` random playouts
+///
+/// # Panics
+/// If the game is already over
+#[must_use]
+pub fn search(position: Position, iterations: u32, random: &mut Random) -> u8 {
+    search_until(position, iterations, None, random)
+}
+
+/// Like [`search`], but stops early at `deadline` after at least one playout
+///
+/// # Panics
+/// If the game is already over
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn search_until(
+    position: Position,
+    iterations: u32,
+    deadline: Option<Instant>,
+    random: &mut Random,
+) -> u8 {
+    let mut nodes = vec![Node {
+        position,
+        parent: None,
+        index: 0,
+        children: Vec::new(),
+        untried: position.moves(),
+        visits: 0,
+        reward: 0.0,
+    }];
+    assert!(nodes[0].untried != BitBoard::EMPTY, "the game is over");
+
+    for iteration in 0..iterations.max(1) {
+        if iteration > 0 && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            break;
+        }
+        // Selection
+        let mut current = 0;
+        while nodes[current].untried == BitBoard::EMPTY && !nodes[current].children.is_empty() {
+            let parent_visits = f64::from(nodes[current].visits).ln();
+            current = *nodes[current]
+                .children
+                .iter()
+                .max_by(|a, b| {
+                    let score = |node: &Node| {
+                        let visits = f64::from(node.visits);
+                        node.reward / visits + (2.0 * parent_visits / visits).sqrt()
+                    };
+                    score(&nodes[**a]).total_cmp(&score(&nodes[**b]))
+                })
+                .unwrap();
+        }
+
+        // Expansion
+        if nodes[current].untried != BitBoard::EMPTY {
+            let index = random.pick(nodes[current].untried);
+            nodes[current].untried &= !BitBoard::new(1 << index);
+            let child = nodes[current].position.play(index);
+            nodes.push(Node {
+                position: child,
+                parent: Some(current),
+                index,
+                children: Vec::new(),
+                untried: child.moves(),
+                visits: 0,
+                reward: 0.0,
+            });
+            let child_index = nodes.len() - 1;
+            nodes[current].children.push(child_index);
+            current = child_index;
+        }
+
+        // Simulation and backpropagation
+        let winner = playout(nodes[current].position, random);
+        let mut node = Some(current);
+        while let Some(index) = node {
+            let moved_x = !nodes[index].position.x_to_move;
+            nodes[index].visits += 1;
+            nodes[index].reward += match winner {
+                Some(x_won) if x_won == moved_x => 1.0,
+                Some(_) => 0.0,
+                None => 0.5,
+            };
+            node = nodes[index].parent;
+        }
+    }
+
+    nodes[0]
+        .children
+        .iter()
+        .map(|child| &nodes[*child])
+        .max_by_key(|child| child.visits)
+        .unwrap()
+        .index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Board;
+
+    fn position(notation: &str) -> Position {
+        Position::new(notation.parse::<Board>().unwrap())
+    }
+
+    #[test]
+    fn takes_a_win_and_blocks_a_loss() {
+        let mut random = Random::new(1);
+        // X completes the a1–c1 row
+        assert_eq!(search(position("---/OO-/XX-"), 2000, &mut random), 2);
+        // O has to stop X's a1–c1 row
+        assert_eq!(search(position("O--/---/XX-"), 2000, &mut random), 2);
+    }
+
+    #[test]
+    fn a_passed_deadline_still_gives_a_move() {
+        let mut random = Random::new(1);
+        let start = Position::START;
+        let index = search_until(start, 1_000_000, Some(Instant::now()), &mut random);
+        assert!(start.moves().get(index));
+    }
+
+    #[test]
+    #[should_panic = "the game is over"]
+    fn needs_a_move_to_make() {
+        let _ = search(position("XXX/OO-/---"), 10, &mut Random::new(1));
+    }
+}
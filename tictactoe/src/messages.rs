@@ -0,0 +1,782 @@
+use std::fmt::Write as _;
+use tictactoe::board::line::Line;
+use tictactoe::board::square_name;
+
+use crate::save::LoadError;
+
+/// Language of everything the game says
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Spanish,
+    German,
+}
+
+impl Language {
+    /// Looks up a language by code or name, such as `es` or `spanish`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_lowercase().as_str() {
+            "en" | "english" => Self::English,
+            "es" | "spanish" | "español" => Self::Spanish,
+            "de" | "german" | "deutsch" => Self::German,
+            _ => return None,
+        })
+    }
+
+    /// From `LC_ALL`, `LC_MESSAGES` or `LANG`, such as `es_ES.UTF-8`, in that order, falling back to English
+    pub fn from_environment() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|variable| std::env::var(variable).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| {
+                let code = value.split(['_', '.', '@']).next().unwrap_or_default();
+                Self::from_name(code)
+            })
+            .unwrap_or(Self::English)
+    }
+
+    /// Whether an answer means yes. `y` always counts, since it's what the English prompt asks for
+    pub fn is_yes(self, answer: &str) -> bool {
+        let answer = answer.trim().to_lowercase();
+        let yes: &[&str] = match self {
+            Self::English => &["y", "yes"],
+            Self::Spanish => &["y", "s", "si", "sí"],
+            Self::German => &["y", "j", "ja"],
+        };
+        yes.contains(&answer.as_str())
+    }
+
+    /// Headings of the scoreboard columns: player, won, lost and drawn
+    pub const fn score_headings(self) -> [&'static str; 4] {
+        match self {
+            Self::English => ["Player", "Won", "Lost", "Drawn"],
+            Self::Spanish => ["Jugador", "Ganadas", "Perdidas", "Tablas"],
+            Self::German => ["Spieler", "Siege", "Niederlagen", "Remis"],
+        }
+    }
+}
+
+const fn side(x: bool) -> &'static str {
+    if x {
+        "X"
+    } else {
+        "O"
+    }
+}
+
+/// Everything the game says, put into words by [`Message::text`]
+pub enum Message<'a> {
+    /// The side can force a win
+    CanForceWin(bool),
+    Drawable,
+    Draw,
+    /// The side won by completing these lines
+    Wins(bool, &'a [Line]),
+    /// The side won because the other ran out of time
+    WinsOnTime(bool),
+    ToMove(bool),
+    Occupied,
+    GameOver,
+    /// [`Message::GameOver`] for the full-screen mode, where new is a key
+    GameOverPress,
+    NothingToUndo,
+    NothingToRedo,
+    /// Squares already named in the input scheme
+    BestMoves(&'a str),
+    /// A square that throws away the win if true or the draw if false, and the better squares
+    ThrowsAway(&'a str, bool, &'a str),
+    PlayAnyway,
+    TakenBack,
+    /// A coach warning in the full-screen mode, where the move is confirmed by choosing it again
+    ChooseAgain(&'a str),
+    /// Explains the heatmap for the side to move, and whether distances are shown
+    MapLegend(bool, bool),
+    FinalScore(u32),
+    /// Games played and games in the match
+    Score(u32, u32),
+    /// Who plays X and O after a swap
+    NowPlayedBy(&'a str, &'a str),
+    Human,
+    Saved(&'a str),
+    CouldntSave(&'a str, &'a str),
+    CouldntLoad(&'a str, &'a LoadError),
+    CouldntResume(&'a str, &'a LoadError),
+    /// The full list of commands, with an example square
+    Help(&'a str),
+    EmptyInput,
+    UnknownCommand(&'a str),
+    /// `save` or `load` without a file
+    MissingFile(&'a str),
+    Title,
+    Moves,
+    Keys,
+    NeedsTerminal,
+    NoRawMode,
+    ReplayKeys,
+    HasWon(bool),
+    Drawn,
+    /// Moves in the game, and how the start stands
+    ReplayStart(usize, &'a str),
+    /// Ply, plies in the game, the side that moved and its square
+    Ply(usize, usize, bool, &'a str),
+    Before(&'a str),
+    After(&'a str),
+    ResultChanged,
+    AlreadyAtStart,
+    AlreadyAtEnd,
+    CouldntReplay(&'a str, &'a LoadError),
+    /// A square in words, such as "top left"
+    Place(u8),
+    EmptySquare,
+    /// What taking a square leads to for the side to move, with the plies until the end if shown
+    Leads(i8, Option<u8>),
+    /// A row in words: its label if the input scheme numbers rows, its index from the bottom, and its squares
+    Row(Option<char>, u8, &'a str),
+    /// The side that moved and its square, already named
+    Takes(bool, &'a str),
+    /// The side that moved and the squares it can now win on, already named
+    Threatens(bool, &'a [String]),
+    /// Who wins with best play, `None` for a draw, and in how many plies
+    Outlook(Option<bool>, u8),
+    /// What the squares described in words mean, for the side to move
+    WordsLegend(bool),
+    /// Time left for X and O, already formatted
+    TimeLeft(&'a str, &'a str),
+    /// How many moves were just taken back
+    MovesUndone(u8),
+    /// A new game began, and whether X moves first
+    BoardReset(bool),
+    /// The number of a scripted move that can't be played, from 1, and what it was
+    IllegalScriptMove(usize, &'a str),
+    /// Where the moves of a scripted game were to be read from, and why they couldn't be
+    CouldntReadMoves(&'a str, &'a str),
+}
+
+impl Message<'_> {
+    pub fn text(&self, language: Language) -> String {
+        match language {
+            Language::English => self.english(),
+            Language::Spanish => self.spanish(),
+            Language::German => self.german(),
+        }
+    }
+
+    /// Such as "the a1–c3 diagonal and the a2–c2 row"
+    fn lines(lines: &[Line], language: Language) -> String {
+        let names: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                let [first, _, last] = line.squares();
+                let squares = format!("{}–{}", square_name(first), square_name(last));
+                let kind = match (line, language) {
+                    (Line::Row(_), Language::English) => "row",
+                    (Line::Row(_), Language::Spanish) => "fila",
+                    (Line::Row(_), Language::German) => "Reihe",
+                    (Line::Column(_), Language::English) => "column",
+                    (Line::Column(_), Language::Spanish) => "columna",
+                    (Line::Column(_), Language::German) => "Spalte",
+                    (_, Language::English | Language::Spanish) => "diagonal",
+                    (_, Language::German) => "Diagonale",
+                };
+                match language {
+                    Language::English => format!("the {squares} {kind}"),
+                    Language::Spanish => format!("la {kind} {squares}"),
+                    Language::German => format!("der {kind} {squares}"),
+                }
+            })
+            .collect();
+        names.join(Self::and(language))
+    }
+
+    const fn and(language: Language) -> &'static str {
+        match language {
+            Language::English => " and ",
+            Language::Spanish => " y ",
+            Language::German => " und ",
+        }
+    }
+
+    /// Lines of the command list, each a command and what it does
+    fn help(intro: String, commands: [&str; 12]) -> String {
+        let names = [
+            "undo",
+            "redo",
+            "hint",
+            "map",
+            "map plies",
+            "new",
+            "swap",
+            "show",
+            "save FILE",
+            "load FILE",
+            "help",
+            "quit",
+        ];
+        let mut help = intro;
+        for (name, description) in names.iter().zip(commands) {
+            write!(help, "\n  {name:<10} {description}").unwrap();
+        }
+        help
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn english(&self) -> String {
+        match self {
+            Self::CanForceWin(x) => format!("{} can force a win", side(*x)),
+            Self::Drawable => String::from("This can be drawn"),
+            Self::Draw => String::from("Draw!"),
+            Self::Wins(x, lines) => format!(
+                "{} wins on {}!",
+                side(*x),
+                Self::lines(lines, Language::English)
+            ),
+            Self::WinsOnTime(x) => format!("{} wins on time!", side(*x)),
+            Self::ToMove(x) => format!("{} to move", side(*x)),
+            Self::Occupied => String::from("Occupied"),
+            Self::GameOver => String::from("The game is over, type new to play again"),
+            Self::GameOverPress => String::from("The game is over, press n to play again"),
+            Self::NothingToUndo => String::from("Nothing to undo"),
+            Self::NothingToRedo => String::from("Nothing to redo"),
+            Self::BestMoves(names) => format!("Best moves: {names}"),
+            Self::ThrowsAway(square, win, better) => format!(
+                "{square} throws away the {}. Better: {better}",
+                if *win { "win" } else { "draw" }
+            ),
+            Self::PlayAnyway => String::from(
+                "Play it anyway? Type y to play it, anything else to take it back: ",
+            ),
+            Self::TakenBack => String::from("Taken back"),
+            Self::ChooseAgain(warning) => {
+                format!("{warning}. Choose it again to play it anyway")
+            }
+            Self::MapLegend(x, distances) => format!(
+                "W, D or L: whether {} wins, draws or loses after playing there{}",
+                side(*x),
+                if *distances {
+                    ", followed by the plies until the game ends"
+                } else {
+                    ""
+                }
+            ),
+            Self::FinalScore(played) => format!("Final score after {played} games:"),
+            Self::Score(played, games) => format!("Score after {played} of {games} games:"),
+            Self::NowPlayedBy(x, o) => format!("X is now played by {x}, O by {o}"),
+            Self::Human => String::from("human"),
+            Self::Saved(path) => format!("Saved to {path}"),
+            Self::CouldntSave(path, error) => format!("Couldn't save to {path}: {error}"),
+            Self::CouldntLoad(path, error) => {
+                format!("Couldn't load {path}: {}", Self::load_error_english(error))
+            }
+            Self::CouldntResume(path, error) => {
+                format!("Couldn't resume {path}: {}", Self::load_error_english(error))
+            }
+            Self::Help(example) => Self::help(
+                format!("Type a square such as {example} to play there, or one of:"),
+                [
+                    "take back your last move",
+                    "play a move you took back again",
+                    "show the best moves",
+                    "show W, D or L in each empty square for the result of playing there",
+                    "the same, with the plies until the game ends",
+                    "start a new game",
+                    "change which side the AI plays",
+                    "print the board again",
+                    "write the game to FILE",
+                    "continue the game saved in FILE",
+                    "show this list",
+                    "leave the game",
+                ],
+            ),
+            Self::EmptyInput => String::from("Type a square, or help for a list of commands"),
+            Self::UnknownCommand(input) => {
+                format!("Unknown command {input:?}, type help for a list of commands")
+            }
+            Self::MissingFile(command) => format!("{command} needs a file name"),
+            Self::Title => String::from("Tic-tac-toe"),
+            Self::Moves => String::from("Moves"),
+            Self::Keys => String::from(
+                "arrows/click move  enter place  u undo  r redo  h hint  m map  n new  s swap  q quit",
+            ),
+            Self::NeedsTerminal => String::from("--tui needs a terminal"),
+            Self::NoRawMode => String::from("Couldn't switch the terminal to raw mode"),
+            Self::ReplayKeys => String::from(
+                "Enter or n for the next move, b to go back, a number to jump to that ply, s start, e end, q quit",
+            ),
+            Self::HasWon(x) => format!("{} has won", side(*x)),
+            Self::Drawn => String::from("Drawn"),
+            Self::ReplayStart(moves, result) => format!("Start of {moves} moves: {result}"),
+            Self::Ply(ply, plies, x, square) => {
+                format!("Ply {ply} of {plies}: {} {square}", side(*x))
+            }
+            Self::Before(result) => format!("Before: {result}"),
+            Self::After(result) => format!("After:  {result}"),
+            Self::ResultChanged => String::from("** The result changed with this move **"),
+            Self::AlreadyAtStart => String::from("Already at the start"),
+            Self::AlreadyAtEnd => String::from("Already at the end"),
+            Self::CouldntReplay(path, error) => {
+                format!("Couldn't replay {path}: {}", Self::load_error_english(error))
+            }
+            Self::Place(index) => String::from(
+                [
+                    "bottom left",
+                    "bottom middle",
+                    "bottom right",
+                    "middle left",
+                    "centre",
+                    "middle right",
+                    "top left",
+                    "top middle",
+                    "top right",
+                ][*index as usize],
+            ),
+            Self::EmptySquare => String::from("empty"),
+            Self::Leads(result, distance) => {
+                let result = match result {
+                    1 => "win",
+                    -1 => "loss",
+                    _ => "draw",
+                };
+                distance.map_or_else(
+                    || result.to_owned(),
+                    |distance| format!("{result} in {distance}"),
+                )
+            }
+            Self::Row(Some(label), _, squares) => format!("Row {label}: {squares}."),
+            Self::Row(None, row, squares) => format!(
+                "{} row: {squares}.",
+                ["Bottom", "Middle", "Top"][*row as usize]
+            ),
+            Self::Takes(x, square) => format!("{} takes {square}.", side(*x)),
+            Self::MovesUndone(1) => String::from("Took back 1 move."),
+            Self::MovesUndone(count) => format!("Took back {count} moves."),
+            Self::BoardReset(x) => format!("New game, {} moves first.", side(*x)),
+            Self::Threatens(x, squares) => format!(
+                "{} threatens to win on {}.",
+                side(*x),
+                squares.join(Self::and(Language::English))
+            ),
+            Self::Outlook(Some(x), 1) => format!("With best play, {} wins with the next move.", side(*x)),
+            Self::Outlook(Some(x), plies) => {
+                format!("With best play, {} wins in {plies} more moves.", side(*x))
+            }
+            Self::Outlook(None, _) => String::from("With best play, the game is drawn."),
+            Self::WordsLegend(x) => format!(
+                "Each empty square says whether {} wins, draws or loses after playing there.",
+                side(*x)
+            ),
+            Self::TimeLeft(x, o) => format!("X has {x} left, O has {o}."),
+            Self::IllegalScriptMove(number, input) => {
+                format!("Move {number}, {input:?}, isn't legal there")
+            }
+            Self::CouldntReadMoves(path, error) => {
+                format!("Couldn't read moves from {path}: {error}")
+            }
+        }
+    }
+
+    fn load_error_english(error: &LoadError) -> String {
+        match error {
+            LoadError::Io(error) => error.to_string(),
+            LoadError::Header => String::from("not a saved game"),
+            LoadError::Line(number, line) => format!("line {number} isn't valid: {line:?}"),
+            LoadError::Missing(key) => format!("no {key} line"),
+            LoadError::IllegalMove(name) => format!("{name} isn't a legal move there"),
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn spanish(&self) -> String {
+        match self {
+            Self::CanForceWin(x) => format!("{} puede forzar la victoria", side(*x)),
+            Self::Drawable => String::from("Esto puede acabar en tablas"),
+            Self::Draw => String::from("¡Tablas!"),
+            Self::Wins(x, lines) => format!(
+                "¡{} gana con {}!",
+                side(*x),
+                Self::lines(lines, Language::Spanish)
+            ),
+            Self::WinsOnTime(x) => format!("¡{} gana por tiempo!", side(*x)),
+            Self::ToMove(x) => format!("Juega {}", side(*x)),
+            Self::Occupied => String::from("Casilla ocupada"),
+            Self::GameOver => {
+                String::from("La partida ha terminado, escribe new para jugar otra vez")
+            }
+            Self::GameOverPress => {
+                String::from("La partida ha terminado, pulsa n para jugar otra vez")
+            }
+            Self::NothingToUndo => String::from("No hay nada que deshacer"),
+            Self::NothingToRedo => String::from("No hay nada que rehacer"),
+            Self::BestMoves(names) => format!("Mejores jugadas: {names}"),
+            Self::ThrowsAway(square, win, better) => format!(
+                "{square} deja escapar {}. Mejor: {better}",
+                if *win { "la victoria" } else { "las tablas" }
+            ),
+            Self::PlayAnyway => String::from(
+                "¿Jugarla de todos modos? Escribe s para jugarla, cualquier otra cosa para retirarla: ",
+            ),
+            Self::TakenBack => String::from("Jugada retirada"),
+            Self::ChooseAgain(warning) => {
+                format!("{warning}. Elígela otra vez para jugarla de todos modos")
+            }
+            Self::MapLegend(x, distances) => format!(
+                "W, D o L: si {} gana, hace tablas o pierde al jugar ahí{}",
+                side(*x),
+                if *distances {
+                    ", seguido de las jugadas que quedan hasta el final"
+                } else {
+                    ""
+                }
+            ),
+            Self::FinalScore(played) => format!("Resultado final tras {played} partidas:"),
+            Self::Score(played, games) => {
+                format!("Resultado tras {played} de {games} partidas:")
+            }
+            Self::NowPlayedBy(x, o) => format!("Ahora X lo juega {x} y O {o}"),
+            Self::Human => String::from("humano"),
+            Self::Saved(path) => format!("Guardada en {path}"),
+            Self::CouldntSave(path, error) => format!("No se pudo guardar en {path}: {error}"),
+            Self::CouldntLoad(path, error) => {
+                format!("No se pudo cargar {path}: {}", Self::load_error_spanish(error))
+            }
+            Self::CouldntResume(path, error) => format!(
+                "No se pudo continuar {path}: {}",
+                Self::load_error_spanish(error)
+            ),
+            Self::Help(example) => Self::help(
+                format!("Escribe una casilla como {example} para jugar ahí, o una de estas órdenes:"),
+                [
+                    "retira tu última jugada",
+                    "vuelve a jugar una jugada retirada",
+                    "muestra las mejores jugadas",
+                    "muestra W, D o L en cada casilla vacía según el resultado de jugar ahí",
+                    "lo mismo, con las jugadas que quedan hasta el final",
+                    "empieza una partida nueva",
+                    "cambia el lado que juega la IA",
+                    "vuelve a mostrar el tablero",
+                    "guarda la partida en FILE",
+                    "continúa la partida guardada en FILE",
+                    "muestra esta lista",
+                    "sale del juego",
+                ],
+            ),
+            Self::EmptyInput => {
+                String::from("Escribe una casilla, o help para ver la lista de órdenes")
+            }
+            Self::UnknownCommand(input) => {
+                format!("Orden desconocida {input:?}, escribe help para ver la lista de órdenes")
+            }
+            Self::MissingFile(command) => format!("{command} necesita un nombre de archivo"),
+            Self::Title => String::from("Tres en raya"),
+            Self::Moves => String::from("Jugadas"),
+            Self::Keys => String::from(
+                "flechas/clic mover  enter jugar  u deshacer  r rehacer  h pista  m mapa  n nueva  s cambiar  q salir",
+            ),
+            Self::NeedsTerminal => String::from("--tui necesita un terminal"),
+            Self::NoRawMode => String::from("No se pudo poner el terminal en modo raw"),
+            Self::ReplayKeys => String::from(
+                "Enter o n para la siguiente jugada, b para volver, un número para ir a esa jugada, s inicio, e final, q salir",
+            ),
+            Self::HasWon(x) => format!("{} ha ganado", side(*x)),
+            Self::Drawn => String::from("Tablas"),
+            Self::ReplayStart(moves, result) => {
+                format!("Inicio de {moves} jugadas: {result}")
+            }
+            Self::Ply(ply, plies, x, square) => {
+                format!("Jugada {ply} de {plies}: {} {square}", side(*x))
+            }
+            Self::Before(result) => format!("Antes:   {result}"),
+            Self::After(result) => format!("Después: {result}"),
+            Self::ResultChanged => String::from("** Esta jugada cambió el resultado **"),
+            Self::AlreadyAtStart => String::from("Ya estás al principio"),
+            Self::AlreadyAtEnd => String::from("Ya estás al final"),
+            Self::CouldntReplay(path, error) => format!(
+                "No se pudo reproducir {path}: {}",
+                Self::load_error_spanish(error)
+            ),
+            Self::Place(index) => String::from(
+                [
+                    "abajo izquierda",
+                    "abajo centro",
+                    "abajo derecha",
+                    "centro izquierda",
+                    "centro",
+                    "centro derecha",
+                    "arriba izquierda",
+                    "arriba centro",
+                    "arriba derecha",
+                ][*index as usize],
+            ),
+            Self::EmptySquare => String::from("vacía"),
+            Self::Leads(result, distance) => {
+                let result = match result {
+                    1 => "victoria",
+                    -1 => "derrota",
+                    _ => "tablas",
+                };
+                distance.map_or_else(
+                    || result.to_owned(),
+                    |distance| format!("{result} en {distance}"),
+                )
+            }
+            Self::Row(Some(label), _, squares) => format!("Fila {label}: {squares}."),
+            Self::Row(None, row, squares) => format!(
+                "Fila {}: {squares}.",
+                ["de abajo", "del centro", "de arriba"][*row as usize]
+            ),
+            Self::Takes(x, square) => format!("{} juega en {square}.", side(*x)),
+            Self::MovesUndone(1) => String::from("Se retiró 1 jugada."),
+            Self::MovesUndone(count) => format!("Se retiraron {count} jugadas."),
+            Self::BoardReset(x) => format!("Nueva partida, empieza {}.", side(*x)),
+            Self::Threatens(x, squares) => format!(
+                "{} amenaza con ganar en {}.",
+                side(*x),
+                squares.join(Self::and(Language::Spanish))
+            ),
+            Self::Outlook(Some(x), 1) => {
+                format!("Con el mejor juego, {} gana con la próxima jugada.", side(*x))
+            }
+            Self::Outlook(Some(x), plies) => format!(
+                "Con el mejor juego, {} gana en {plies} jugadas más.",
+                side(*x)
+            ),
+            Self::Outlook(None, _) => {
+                String::from("Con el mejor juego, la partida acaba en tablas.")
+            }
+            Self::WordsLegend(x) => format!(
+                "Cada casilla vacía dice si {} gana, empata o pierde después de jugar ahí.",
+                side(*x)
+            ),
+            Self::TimeLeft(x, o) => format!("A X le quedan {x}, a O {o}."),
+            Self::IllegalScriptMove(number, input) => {
+                format!("La jugada {number}, {input:?}, no es legal ahí")
+            }
+            Self::CouldntReadMoves(path, error) => {
+                format!("No se pudieron leer las jugadas de {path}: {error}")
+            }
+        }
+    }
+
+    fn load_error_spanish(error: &LoadError) -> String {
+        match error {
+            LoadError::Io(error) => error.to_string(),
+            LoadError::Header => String::from("no es una partida guardada"),
+            LoadError::Line(number, line) => format!("la línea {number} no es válida: {line:?}"),
+            LoadError::Missing(key) => format!("falta la línea {key}"),
+            LoadError::IllegalMove(name) => format!("{name} no es una jugada legal ahí"),
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn german(&self) -> String {
+        match self {
+            Self::CanForceWin(x) => format!("{} kann den Sieg erzwingen", side(*x)),
+            Self::Drawable => String::from("Das kann remis enden"),
+            Self::Draw => String::from("Remis!"),
+            Self::Wins(x, lines) => format!(
+                "{} gewinnt mit {}!",
+                side(*x),
+                Self::lines(lines, Language::German)
+            ),
+            Self::WinsOnTime(x) => format!("{} gewinnt auf Zeit!", side(*x)),
+            Self::ToMove(x) => format!("{} ist am Zug", side(*x)),
+            Self::Occupied => String::from("Feld besetzt"),
+            Self::GameOver => {
+                String::from("Das Spiel ist vorbei, tippe new für ein neues Spiel")
+            }
+            Self::GameOverPress => {
+                String::from("Das Spiel ist vorbei, drücke n für ein neues Spiel")
+            }
+            Self::NothingToUndo => String::from("Nichts zum Zurücknehmen"),
+            Self::NothingToRedo => String::from("Nichts zum Wiederholen"),
+            Self::BestMoves(names) => format!("Beste Züge: {names}"),
+            Self::ThrowsAway(square, win, better) => format!(
+                "{square} verschenkt {}. Besser: {better}",
+                if *win { "den Sieg" } else { "das Remis" }
+            ),
+            Self::PlayAnyway => String::from(
+                "Trotzdem spielen? Tippe j zum Spielen, alles andere nimmt den Zug zurück: ",
+            ),
+            Self::TakenBack => String::from("Zug zurückgenommen"),
+            Self::ChooseAgain(warning) => {
+                format!("{warning}. Wähle das Feld noch einmal, um trotzdem zu spielen")
+            }
+            Self::MapLegend(x, distances) => format!(
+                "W, D oder L: ob {} nach einem Zug dorthin gewinnt, remis spielt oder verliert{}",
+                side(*x),
+                if *distances {
+                    ", gefolgt von den Halbzügen bis zum Spielende"
+                } else {
+                    ""
+                }
+            ),
+            Self::FinalScore(played) => format!("Endstand nach {played} Spielen:"),
+            Self::Score(played, games) => format!("Stand nach {played} von {games} Spielen:"),
+            Self::NowPlayedBy(x, o) => format!("X spielt jetzt {x}, O spielt {o}"),
+            Self::Human => String::from("Mensch"),
+            Self::Saved(path) => format!("Gespeichert in {path}"),
+            Self::CouldntSave(path, error) => {
+                format!("Speichern in {path} fehlgeschlagen: {error}")
+            }
+            Self::CouldntLoad(path, error) => format!(
+                "Laden von {path} fehlgeschlagen: {}",
+                Self::load_error_german(error)
+            ),
+            Self::CouldntResume(path, error) => format!(
+                "Fortsetzen von {path} fehlgeschlagen: {}",
+                Self::load_error_german(error)
+            ),
+            Self::Help(example) => Self::help(
+                format!("Tippe ein Feld wie {example}, um dort zu spielen, oder einen dieser Befehle:"),
+                [
+                    "nimmt deinen letzten Zug zurück",
+                    "spielt einen zurückgenommenen Zug wieder",
+                    "zeigt die besten Züge",
+                    "zeigt W, D oder L in jedem leeren Feld für das Ergebnis eines Zugs dorthin",
+                    "dasselbe, mit den Halbzügen bis zum Spielende",
+                    "beginnt ein neues Spiel",
+                    "wechselt die Seite, die die KI spielt",
+                    "zeigt das Brett noch einmal",
+                    "speichert das Spiel in FILE",
+                    "setzt das in FILE gespeicherte Spiel fort",
+                    "zeigt diese Liste",
+                    "beendet das Spiel",
+                ],
+            ),
+            Self::EmptyInput => String::from("Tippe ein Feld, oder help für eine Befehlsliste"),
+            Self::UnknownCommand(input) => {
+                format!("Unbekannter Befehl {input:?}, tippe help für eine Befehlsliste")
+            }
+            Self::MissingFile(command) => format!("{command} braucht einen Dateinamen"),
+            Self::Title => String::from("Tic-Tac-Toe"),
+            Self::Moves => String::from("Züge"),
+            Self::Keys => String::from(
+                "Pfeile/Klick bewegen  Enter setzen  u zurück  r wiederholen  h Tipp  m Karte  n neu  s tauschen  q beenden",
+            ),
+            Self::NeedsTerminal => String::from("--tui braucht ein Terminal"),
+            Self::NoRawMode => {
+                String::from("Das Terminal konnte nicht in den Raw-Modus geschaltet werden")
+            }
+            Self::ReplayKeys => String::from(
+                "Enter oder n für den nächsten Zug, b zurück, eine Zahl springt zu diesem Halbzug, s Anfang, e Ende, q beenden",
+            ),
+            Self::HasWon(x) => format!("{} hat gewonnen", side(*x)),
+            Self::Drawn => String::from("Remis"),
+            Self::ReplayStart(moves, result) => format!("Anfang von {moves} Zügen: {result}"),
+            Self::Ply(ply, plies, x, square) => {
+                format!("Halbzug {ply} von {plies}: {} {square}", side(*x))
+            }
+            Self::Before(result) => format!("Vorher:  {result}"),
+            Self::After(result) => format!("Nachher: {result}"),
+            Self::ResultChanged => String::from("** Dieser Zug hat das Ergebnis geändert **"),
+            Self::AlreadyAtStart => String::from("Schon am Anfang"),
+            Self::AlreadyAtEnd => String::from("Schon am Ende"),
+            Self::CouldntReplay(path, error) => format!(
+                "Abspielen von {path} fehlgeschlagen: {}",
+                Self::load_error_german(error)
+            ),
+            Self::Place(index) => String::from(
+                [
+                    "unten links",
+                    "unten Mitte",
+                    "unten rechts",
+                    "Mitte links",
+                    "Mitte",
+                    "Mitte rechts",
+                    "oben links",
+                    "oben Mitte",
+                    "oben rechts",
+                ][*index as usize],
+            ),
+            Self::EmptySquare => String::from("leer"),
+            Self::Leads(result, distance) => {
+                let result = match result {
+                    1 => "Sieg",
+                    -1 => "Niederlage",
+                    _ => "Remis",
+                };
+                distance.map_or_else(
+                    || result.to_owned(),
+                    |distance| format!("{result} in {distance}"),
+                )
+            }
+            Self::Row(Some(label), _, squares) => format!("Reihe {label}: {squares}."),
+            Self::Row(None, row, squares) => format!(
+                "{} Reihe: {squares}.",
+                ["Untere", "Mittlere", "Obere"][*row as usize]
+            ),
+            Self::Takes(x, square) => format!("{} setzt auf {square}.", side(*x)),
+            Self::MovesUndone(1) => String::from("1 Zug zurückgenommen."),
+            Self::MovesUndone(count) => format!("{count} Züge zurückgenommen."),
+            Self::BoardReset(x) => format!("Neues Spiel, {} beginnt.", side(*x)),
+            Self::Threatens(x, squares) => format!(
+                "{} droht, auf {} zu gewinnen.",
+                side(*x),
+                squares.join(Self::and(Language::German))
+            ),
+            Self::Outlook(Some(x), 1) => {
+                format!("Bei bestem Spiel gewinnt {} mit dem nächsten Zug.", side(*x))
+            }
+            Self::Outlook(Some(x), plies) => format!(
+                "Bei bestem Spiel gewinnt {} in {plies} weiteren Zügen.",
+                side(*x)
+            ),
+            Self::Outlook(None, _) => String::from("Bei bestem Spiel endet die Partie remis."),
+            Self::WordsLegend(x) => format!(
+                "Jedes leere Feld sagt, ob {} nach einem Zug dorthin gewinnt, remis spielt oder verliert.",
+                side(*x)
+            ),
+            Self::TimeLeft(x, o) => format!("X hat noch {x}, O {o}."),
+            Self::IllegalScriptMove(number, input) => {
+                format!("Zug {number}, {input:?}, ist dort nicht erlaubt")
+            }
+            Self::CouldntReadMoves(path, error) => {
+                format!("Die Züge aus {path} konnten nicht gelesen werden: {error}")
+            }
+        }
+    }
+
+    fn load_error_german(error: &LoadError) -> String {
+        match error {
+            LoadError::Io(error) => error.to_string(),
+            LoadError::Header => String::from("kein gespeichertes Spiel"),
+            LoadError::Line(number, line) => format!("Zeile {number} ist ungültig: {line:?}"),
+            LoadError::Missing(key) => format!("keine {key}-Zeile"),
+            LoadError::IllegalMove(name) => format!("{name} ist dort kein gültiger Zug"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_languages_and_answers() {
+        assert!(Language::from_name("ES") == Some(Language::Spanish));
+        assert!(Language::from_name("deutsch") == Some(Language::German));
+        assert!(Language::from_name("fr").is_none());
+        assert!(Language::Spanish.is_yes(" Sí "));
+        assert!(Language::German.is_yes("y"));
+        assert!(!Language::English.is_yes("ja"));
+        assert!(!Language::English.is_yes(""));
+    }
+
+    #[test]
+    fn names_winning_lines() {
+        let lines = [Line::Diagonal, Line::Row(1)];
+        let wins = Message::Wins(true, &lines);
+        assert_eq!(
+            wins.text(Language::English),
+            "X wins on the a1–c3 diagonal and the a2–c2 row!"
+        );
+        assert_eq!(
+            wins.text(Language::Spanish),
+            "¡X gana con la diagonal a1–c3 y la fila a2–c2!"
+        );
+        assert_eq!(
+            Message::Wins(false, &[Line::Column(2)]).text(Language::German),
+            "O gewinnt mit der Spalte c1–c3!"
+        );
+    }
+}
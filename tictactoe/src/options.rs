@@ -0,0 +1,288 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tictactoe::agent::{Agent, MAX_LEVEL};
+use tictactoe::board::render::{BoardRenderer, Style};
+use tictactoe::board::scheme::Scheme;
+
+use crate::clock::TimeControl;
+use crate::messages::Language;
+use crate::script::Moves;
+
+pub const USAGE: &str = "\
+Usage: tictactoe [options]
+       tictactoe replay FILE [--style S] [--input S] [--colour WHEN] [--lang L]
+  --x PLAYER    who plays X: human, perfect, random or mcts (default perfect)
+  --o PLAYER    who plays O (default human)
+  --first SIDE  who moves first, x or o (default x)
+  --level N     strength of the computer players, from 1 to 10 (default 10)
+  --seed N      seed for the computer players' random choices
+  --games N     play a match of N games, alternating who moves first
+  --stop-early  end the match as soon as nobody can catch up
+  --coach       warn before a move that throws away a win or a draw, and offer to take it back
+  --time B+I    give each side B seconds, plus I more after each of their moves
+  --movetime N  give each move N seconds. Running out of time loses, though a human's
+                clock is only checked once they type a line or press a key
+  --resume FILE continue a game saved with the save command, with its players and clocks
+  --moves LIST  play these moves, such as \"b2 a1 c3\", then let the computer players
+                finish. Prints the final position and exits with 10 if X won, 11 if O won,
+                12 for a draw, 13 if a human is to move and 3 for an illegal move
+  --moves-file FILE  the same, with the moves read from FILE, or stdin for -
+  --protocol    speak the engine protocol on stdin and stdout for other programs, with
+                the --x player searching
+  --json        answer one JSON request per line on stdin with one JSON response per line,
+                for other programs. The README lists the requests
+  --tui         full-screen mode, played with the arrow keys or the mouse
+  --style S     board drawing, ascii or unicode (default ascii)
+  --input S     how squares are typed: algebraic (b2, B2 or 2b), numpad (7 8 9 on top),
+                reading (1 2 3 on top) or rowcol (row,column from the top left)
+  --colour WHEN colour the marks: auto, always or never (default auto, which respects NO_COLOR)
+  --accessible  describe the board in words for screen readers. Squares can also be
+                typed in words anywhere, such as top left or centre
+  --lang L      language of the game: en, es or de (default from LANG)
+  --help        show this message";
+
+#[allow(clippy::struct_excessive_bools)]
+pub struct Options {
+    /// `None` for a human
+    pub x: Option<Agent>,
+    pub o: Option<Agent>,
+    /// Strength the computer players were built with
+    pub level: u8,
+    pub x_first: bool,
+    pub seed: u64,
+    pub games: u32,
+    pub stop_early: bool,
+    pub coach: bool,
+    pub time_control: Option<TimeControl>,
+    /// Saved game to continue
+    pub resume: Option<String>,
+    /// Moves of a scripted game, played without asking for input
+    pub moves: Option<Moves>,
+    /// Speak the engine protocol instead of playing
+    pub protocol: bool,
+    /// Answer JSON requests instead of playing
+    pub json: bool,
+    pub tui: bool,
+    pub renderer: BoardRenderer,
+    pub language: Language,
+    /// Describe the board in words instead of drawing it
+    pub accessible: bool,
+}
+
+fn player(name: &str, level: u8) -> Result<Option<Agent>, String> {
+    if name == "human" {
+        return Ok(None);
+    }
+    Agent::from_name(name, level)
+        .map(Some)
+        .ok_or_else(|| format!("Unknown player {name:?}"))
+}
+
+impl Options {
+    #[allow(clippy::too_many_lines)]
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let (mut x, mut o) = (String::from("perfect"), String::from("human"));
+        let mut x_first = None;
+        let mut level = MAX_LEVEL;
+        let mut seed = None;
+        let mut games = 1;
+        let mut stop_early = false;
+        let mut coach = false;
+        let mut time_control = None;
+        let mut resume = None;
+        let mut moves = None;
+        let mut protocol = false;
+        let mut json = false;
+        let mut tui = false;
+        let mut renderer = BoardRenderer::ASCII;
+        let mut colour = None;
+        let mut language = None;
+        let mut accessible = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--x" => x = value()?,
+                "--o" => o = value()?,
+                "--first" => {
+                    x_first = Some(match value()?.as_str() {
+                        "x" | "X" => true,
+                        "o" | "O" => false,
+                        side => return Err(format!("Unknown side {side:?}")),
+                    });
+                }
+                "--level" => {
+                    level = value()?
+                        .parse()
+                        .ok()
+                        .filter(|level| (1..=MAX_LEVEL).contains(level))
+                        .ok_or_else(|| format!("--level needs a number from 1 to {MAX_LEVEL}"))?;
+                }
+                "--seed" => {
+                    seed = Some(value()?.parse().map_err(|_| "--seed needs a number")?);
+                }
+                "--games" => {
+                    games = value()?
+                        .parse()
+                        .ok()
+                        .filter(|games| *games > 0)
+                        .ok_or("--games needs a positive number")?;
+                }
+                "--stop-early" => stop_early = true,
+                "--coach" => coach = true,
+                "--time" => {
+                    time_control = Some(
+                        TimeControl::parse_fischer(&value()?)
+                            .ok_or("--time needs seconds and an increment, such as 60+2")?,
+                    );
+                }
+                "--movetime" => {
+                    time_control = Some(TimeControl::PerMove(
+                        value()?
+                            .parse()
+                            .ok()
+                            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                            .filter(|time| !time.is_zero())
+                            .ok_or("--movetime needs a positive number of seconds")?,
+                    ));
+                }
+                "--resume" => resume = Some(value()?),
+                "--moves" => moves = Some(Moves::List(value()?)),
+                "--moves-file" => moves = Some(Moves::File(value()?)),
+                "--protocol" => protocol = true,
+                "--json" => json = true,
+                "--tui" => tui = true,
+                "--style" => {
+                    renderer.style = match value()?.as_str() {
+                        "ascii" => Style::Ascii,
+                        "unicode" => Style::Unicode,
+                        style => return Err(format!("Unknown style {style:?}")),
+                    };
+                }
+                "--input" => {
+                    let name = value()?;
+                    renderer.scheme = Scheme::from_name(&name)
+                        .ok_or_else(|| format!("Unknown input scheme {name:?}"))?;
+                }
+                "--colour" | "--color" => {
+                    colour = match value()?.as_str() {
+                        "auto" => None,
+                        "always" => Some(true),
+                        "never" => Some(false),
+                        when => return Err(format!("Unknown colour setting {when:?}")),
+                    };
+                }
+                "--lang" => {
+                    let name = value()?;
+                    language = Some(
+                        Language::from_name(&name)
+                            .ok_or_else(|| format!("Unknown language {name:?}"))?,
+                    );
+                }
+                "--accessible" => accessible = true,
+                _ => return Err(format!("Unknown option {arg:?}")),
+            }
+        }
+
+        if accessible && tui {
+            return Err(String::from(
+                "--accessible and --tui can't be used together",
+            ));
+        }
+        if [protocol, json, tui, moves.is_some()]
+            .iter()
+            .filter(|mode| **mode)
+            .count()
+            > 1
+        {
+            return Err(String::from(
+                "Only one of --protocol, --json, --tui and --moves or --moves-file can be used",
+            ));
+        }
+        // The other program sets up every position and plays both sides itself
+        if (protocol || json) && (resume.is_some() || coach || x_first.is_some()) {
+            return Err(String::from(
+                "--resume, --coach and --first can't be used with --protocol or --json",
+            ));
+        }
+        if moves.is_some() && games > 1 {
+            return Err(String::from(
+                "--moves and --moves-file can't be used with --games",
+            ));
+        }
+        if tui && games > 1 {
+            return Err(String::from(
+                "--tui plays a single game, so it can't be used with --games",
+            ));
+        }
+        renderer.colour = colour.unwrap_or_else(BoardRenderer::colour_by_default);
+
+        Ok(Self {
+            x: player(&x, level)?,
+            o: player(&o, level)?,
+            level,
+            x_first: x_first.unwrap_or(true),
+            #[allow(clippy::cast_possible_truncation)]
+            seed: seed.unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |time| time.as_nanos() as u64)
+            }),
+            games,
+            stop_early,
+            coach,
+            time_control,
+            resume,
+            moves,
+            protocol,
+            json,
+            tui,
+            renderer,
+            language: language.unwrap_or_else(Language::from_environment),
+            accessible,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parses_players_and_match() {
+        let options = parse(&[
+            "--x", "human", "--o", "random", "--first", "o", "--games", "3", "--seed", "7",
+        ])
+        .unwrap();
+        assert!(options.x.is_none());
+        assert_eq!(options.o.map(Agent::name), Some("random"));
+        assert!(!options.x_first);
+        assert_eq!(options.games, 3);
+        assert_eq!(options.seed, 7);
+        assert!(parse(&["--x", "nobody"]).is_err());
+        assert!(parse(&["--games", "0"]).is_err());
+        assert!(parse(&["--level", "11"]).is_err());
+        assert!(parse(&["--first"]).is_err());
+    }
+
+    #[test]
+    fn rejects_modes_that_conflict() {
+        for args in [
+            &["--tui", "--games", "3"][..],
+            &["--tui", "--accessible"],
+            &["--tui", "--json"],
+            &["--protocol", "--moves", "b2"],
+            &["--moves", "b2", "--games", "2"],
+            &["--json", "--resume", "game.txt"],
+            &["--json", "--coach"],
+            &["--protocol", "--first", "o"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?}");
+        }
+        assert!(parse(&["--tui", "--games", "1"]).is_ok());
+        assert!(parse(&["--protocol", "--x", "mcts", "--level", "3"]).is_ok());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::position::Position;
+
+/// Counts the move sequences exactly `depth` plies long.
+/// Games that are won or drawn sooner end there and add nothing
+#[must_use]
+pub fn perft(position: Position, depth: u32) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut count = 0;
+    let mut moves = position.moves();
+    while moves.count() != 0 {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = moves.pop() as u8;
+        count += perft(position.play(index), depth - 1);
+    }
+    count
+}
+
+/// [`perft`] split by the first move, as `(square index, count)`
+#[must_use]
+pub fn perft_divide(position: Position, depth: u32) -> Vec<(u8, u64)> {
+    let mut divide = Vec::new();
+    if depth == 0 {
+        return divide;
+    }
+
+    let mut moves = position.moves();
+    while moves.count() != 0 {
+        #[allow(clippy::cast_possible_truncation)]
+        let index = moves.pop() as u8;
+        divide.push((index, perft(position.play(index), depth - 1)));
+    }
+    divide
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_move_sequences() {
+        let counts: Vec<u64> = (0..4).map(|depth| perft(Position::START, depth)).collect();
+        assert_eq!(counts, [1, 9, 72, 504]);
+        // Every game is over by the ninth ply
+        assert_eq!(perft(Position::START, 10), 0);
+    }
+
+    #[test]
+    fn divides_by_first_move() {
+        let divide = perft_divide(Position::START, 3);
+        assert_eq!(divide.len(), 9);
+        assert!(divide.iter().all(|(_, count)| *count == 56));
+        assert!(perft_divide(Position::START, 0).is_empty());
+    }
+}
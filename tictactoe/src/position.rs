@@ -0,0 +1,93 @@
+use crate::board::{bit_board::BitBoard, Board};
+
+/// A board and the side to move
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub board: Board,
+    pub x_to_move: bool,
+}
+
+impl Position {
+    pub const START: Self = Self {
+        board: Board::EMPTY,
+        x_to_move: true,
+    };
+
+    /// Works out the side to move, assuming X moved first
+    #[must_use]
+    pub const fn new(board: Board) -> Self {
+        Self {
+            board,
+            x_to_move: board.x.count() == board.o.count(),
+        }
+    }
+
+    /// `Some(true)` if X has a line, `Some(false)` if O has one
+    #[must_use]
+    pub const fn winner(&self) -> Option<bool> {
+        if self.board.x.has_won() {
+            Some(true)
+        } else if self.board.o.has_won() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some() || self.board.occupied() == BitBoard::FULL
+    }
+
+    /// Empty squares, or none once the game is over
+    #[must_use]
+    pub fn moves(&self) -> BitBoard {
+        if self.winner().is_some() {
+            BitBoard::EMPTY
+        } else {
+            !self.board.occupied() & BitBoard::FULL
+        }
+    }
+
+    /// The position after the side to move takes `index`
+    #[must_use]
+    pub const fn play(&self, index: u8) -> Self {
+        let mut board = self.board;
+        if self.x_to_move {
+            board.x.set(index);
+        } else {
+            board.o.set(index);
+        }
+        Self {
+            board,
+            x_to_move: !self.x_to_move,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plays_and_ends_games() {
+        let position = Position::START.play(0).play(3).play(1).play(4);
+        assert!(position.x_to_move);
+        assert_eq!(Position::new(position.board).x_to_move, position.x_to_move);
+        assert_eq!(position.winner(), None);
+        assert_eq!(position.moves().count(), 5);
+
+        let won = position.play(2);
+        assert_eq!(won.winner(), Some(true));
+        assert!(won.is_over());
+        assert_eq!(won.moves(), BitBoard::EMPTY);
+    }
+
+    #[test]
+    fn a_full_board_is_over() {
+        let drawn = Position::new("XOX/XOO/OXX".parse().unwrap());
+        assert_eq!(drawn.winner(), None);
+        assert!(drawn.is_over());
+        assert_eq!(drawn.moves(), BitBoard::EMPTY);
+    }
+}
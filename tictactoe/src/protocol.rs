@@ -0,0 +1,286 @@
+use std::fmt::Write as _;
+use std::io::{self, BufRead};
+use std::time::Duration;
+use tictactoe::agent::{Agent, MAX_LEVEL};
+use tictactoe::board::scheme::Scheme;
+use tictactoe::board::{parse_square, square_name, Board};
+use tictactoe::evaluation::{distance, evaluate, perfect_moves};
+use tictactoe::position::Position;
+use tictactoe::random::Random;
+
+/// The engine behind `--protocol`: a position and the computer player that searches it
+struct Session {
+    position: Position,
+    /// Name of the computer player, kept so a new level can rebuild it
+    player: String,
+    level: u8,
+    agent: Agent,
+    random: Random,
+}
+
+/// Parses `startpos` or board notation such as `X-O/-X-/--O`, optionally followed by the side
+/// to move, `x` or `o`, and then `moves` and the squares played from there
+fn parse_position(words: &[&str]) -> Result<Position, String> {
+    let (&notation, mut rest) = words
+        .split_first()
+        .ok_or("position needs startpos or a board")?;
+    let board = if notation == "startpos" {
+        Board::EMPTY
+    } else {
+        notation
+            .parse::<Board>()
+            .map_err(|error| format!("invalid board {notation:?}: {error}"))?
+    };
+    let (x, o) = (board.x.count(), board.o.count());
+    if x.abs_diff(o) > 1 || (board.x.has_won() && board.o.has_won()) {
+        return Err(format!("{notation:?} can't come up in a game"));
+    }
+
+    // Equal counts mean X moved first, unless the side to move says otherwise
+    let x_to_move = match rest.first() {
+        Some(&side @ ("x" | "o")) => {
+            rest = &rest[1..];
+            side == "x"
+        }
+        _ => x <= o,
+    };
+    if (x_to_move && x > o) || (!x_to_move && o > x) {
+        return Err(String::from("that side can't be to move on this board"));
+    }
+
+    let mut position = Position { board, x_to_move };
+    match rest {
+        [] => {}
+        ["moves", moves @ ..] => {
+            for name in moves {
+                let index = parse_square(name)
+                    .filter(|index| position.moves().get(*index))
+                    .ok_or_else(|| format!("{name} isn't a legal move there"))?;
+                position = position.play(index);
+            }
+        }
+        _ => return Err(format!("expected moves, found {:?}", rest.join(" "))),
+    }
+    Ok(position)
+}
+
+impl Session {
+    /// The reply to `protocol`: the engine's name and options
+    fn introduction(&self) -> String {
+        let mut reply = format!("id name tictactoe {}\n", env!("CARGO_PKG_VERSION"));
+        writeln!(
+            reply,
+            "option name Player type combo default {} var perfect var random var mcts",
+            self.player
+        )
+        .unwrap();
+        writeln!(
+            reply,
+            "option name Level type spin default {} min 1 max {MAX_LEVEL}",
+            self.level
+        )
+        .unwrap();
+        reply.push_str("option name Seed type string\nprotocolok\n");
+        reply
+    }
+
+    fn set_option(&mut self, words: &[&str]) -> Result<(), String> {
+        let ["name", name, "value", value] = words else {
+            return Err(String::from("setoption needs name <option> value <value>"));
+        };
+        match name.to_lowercase().as_str() {
+            "player" => {
+                self.agent = Agent::from_name(value, self.level)
+                    .ok_or_else(|| format!("unknown player {value:?}"))?;
+                (*value).clone_into(&mut self.player);
+            }
+            "level" => {
+                self.level = value
+                    .parse()
+                    .ok()
+                    .filter(|level| (1..=MAX_LEVEL).contains(level))
+                    .ok_or_else(|| format!("Level needs a number from 1 to {MAX_LEVEL}"))?;
+                self.agent = Agent::from_name(&self.player, self.level).unwrap_or(self.agent);
+            }
+            "seed" => {
+                self.random = Random::new(value.parse().map_err(|_| "Seed needs a number")?);
+            }
+            _ => return Err(format!("unknown option {name:?}")),
+        }
+        Ok(())
+    }
+
+    /// The `info` and `bestmove` replies to `go`
+    fn go(&mut self, words: &[&str]) -> Result<String, String> {
+        let budget = match words {
+            [] => None,
+            ["movetime", milliseconds] => Some(Duration::from_millis(
+                milliseconds
+                    .parse()
+                    .map_err(|_| "movetime needs milliseconds")?,
+            )),
+            _ => return Err(String::from("go takes nothing or movetime <milliseconds>")),
+        };
+        let position = self.position;
+        if position.is_over() {
+            return Ok(String::from("bestmove none\n"));
+        }
+
+        // Perfect-play result for the side to move, and the moves that win fastest or lose slowest,
+        // whichever player searches
+        let result = if position.x_to_move {
+            evaluate(position)
+        } else {
+            -evaluate(position)
+        };
+        let names = Scheme::Algebraic.names(perfect_moves(position));
+        let mut reply = String::new();
+        writeln!(
+            reply,
+            "info score {} plies {} best {}",
+            match result {
+                1 => "win",
+                -1 => "loss",
+                _ => "draw",
+            },
+            distance(position),
+            names.join(" ")
+        )
+        .unwrap();
+
+        let index = match budget {
+            Some(budget) => self.agent.choose_within(position, budget, &mut self.random),
+            None => self.agent.choose(position, &mut self.random),
+        };
+        writeln!(reply, "bestmove {}", square_name(index)).unwrap();
+        Ok(reply)
+    }
+}
+
+/// Speaks the engine protocol described in the README over `input` and `output`, one command
+/// per line, until `quit` or the end of the input. `agent`, at `level`, plays to begin with
+pub fn run(
+    input: impl BufRead,
+    mut output: impl io::Write,
+    agent: Agent,
+    level: u8,
+    seed: u64,
+) -> io::Result<()> {
+    let mut session = Session {
+        position: Position::START,
+        player: agent.name().to_owned(),
+        level,
+        agent,
+        random: Random::new(seed),
+    };
+    for line in input.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, arguments)) = words.split_first() else {
+            continue;
+        };
+        let reply = match command {
+            "protocol" => Ok(session.introduction()),
+            "isready" => Ok(String::from("readyok\n")),
+            "newgame" => {
+                session.position = Position::START;
+                Ok(String::new())
+            }
+            "position" => parse_position(arguments).map(|position| {
+                session.position = position;
+                String::new()
+            }),
+            "go" => session.go(arguments),
+            // Every go has already replied by the time the next line is read
+            "stop" => Ok(String::new()),
+            "setoption" => session.set_option(arguments).map(|()| String::new()),
+            "quit" => break,
+            _ => Err(format!("unknown command {command:?}")),
+        };
+        match reply {
+            Ok(reply) => write!(output, "{reply}")?,
+            Err(error) => writeln!(output, "info string {error}")?,
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(text: &str) -> Result<Position, String> {
+        parse_position(&text.split_whitespace().collect::<Vec<_>>())
+    }
+
+    /// Everything the engine replies to `input`, playing perfectly
+    fn transcript(input: &str) -> String {
+        let mut output = Vec::new();
+        let agent = Agent::Perfect { level: MAX_LEVEL };
+        run(input.as_bytes(), &mut output, agent, MAX_LEVEL, 1).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn parses_positions() {
+        assert!(position("startpos").is_ok_and(|position| position == Position::START));
+        let played = position("startpos moves b2 a1").unwrap();
+        assert!(played.board.x.get(4) && played.board.o.get(0) && played.x_to_move);
+        assert!(position("X--/---/---").is_ok_and(|position| !position.x_to_move));
+        // O moved first on an empty board, or with a mark each
+        assert!(position("---/---/--- o").is_ok_and(|position| !position.x_to_move));
+        assert!(position("X--/---/--O o moves b2").is_ok_and(|position| position.x_to_move));
+    }
+
+    #[test]
+    fn rejects_positions() {
+        for text in [
+            "",
+            "X--/---",
+            "XX-/---/---",
+            "XXX/OOO/---",
+            "X--/---/--- x",
+            "startpos moves b2 b2",
+            "startpos b2",
+        ] {
+            assert!(position(text).is_err(), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn answers_commands() {
+        let output =
+            transcript("protocol\nisready\nposition startpos moves a1 b1 b2 c1\ngo\nquit\ngo\n");
+        assert!(output.starts_with("id name tictactoe "));
+        assert!(output.contains(
+            "option name Player type combo default perfect var perfect var random var mcts\n"
+        ));
+        // a2, c2 and a3 each leave a win, but c3 wins at once
+        assert!(
+            output.ends_with("protocolok\nreadyok\ninfo score win plies 1 best c3\nbestmove c3\n")
+        );
+    }
+
+    #[test]
+    fn sets_options_and_reports_errors() {
+        let output = transcript(
+            "setoption name Level value 0\nsetoption name Player value random\n\
+             setoption name Colour value red\nsetoption name Seed\nfly\n\
+             position XXX/OOO/---\ngo movetime soon\nnewgame\nposition startpos moves \
+             a1 b1 b2 c1 c3\ngo\n",
+        );
+        assert_eq!(
+            output,
+            format!(
+                "info string Level needs a number from 1 to {MAX_LEVEL}\n\
+                 info string unknown option \"Colour\"\n\
+                 info string setoption needs name <option> value <value>\n\
+                 info string unknown command \"fly\"\n\
+                 info string \"XXX/OOO/---\" can't come up in a game\n\
+                 info string movetime needs milliseconds\n\
+                 bestmove none\n"
+            )
+        );
+    }
+}
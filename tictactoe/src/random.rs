@@ -0,0 +1,88 @@
+use crate::board::bit_board::BitBoard;
+
+/// Small xorshift generator, so games can be replayed from a seed
+#[derive(Clone)]
+pub struct Random(u64);
+
+impl Random {
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        // Scramble the seed so that small seeds still give well mixed bits, and it's never zero
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        state = (state ^ (state >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        state = (state ^ (state >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        state ^= state >> 31;
+        Self(if state == 0 { 1 } else { state })
+    }
+
+    /// The current state, to continue the same sequence later with [`Random::from_state`]
+    #[must_use]
+    pub const fn state(&self) -> u64 {
+        self.0
+    }
+
+    /// Continues a sequence from [`Random::state`]. `None` for zero, which xorshift can't leave
+    #[must_use]
+    pub const fn from_state(state: u64) -> Option<Self> {
+        if state == 0 {
+            None
+        } else {
+            Some(Self(state))
+        }
+    }
+
+    pub const fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number from 0 up to but not including `bound`
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn below(&mut self, bound: u32) -> u32 {
+        (((self.next_u64() >> 32) * bound as u64) >> 32) as u32
+    }
+
+    /// Index of a random set bit
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn pick(&mut self, mut bit_board: BitBoard) -> u8 {
+        let mut skip = self.below(bit_board.count());
+        while skip != 0 {
+            let _ = bit_board.pop();
+            skip -= 1;
+        }
+        bit_board.first() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_from_a_seed_or_state() {
+        let mut random = Random::new(0);
+        assert_ne!(random.state(), 0);
+        let mut again = Random::new(0);
+        assert_eq!(random.next_u64(), again.next_u64());
+        let mut resumed = Random::from_state(random.state()).unwrap();
+        assert_eq!(random.next_u64(), resumed.next_u64());
+        assert!(Random::from_state(0).is_none());
+        assert_ne!(Random::new(1).next_u64(), Random::new(2).next_u64());
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut random = Random::new(3);
+        let squares = BitBoard::new(0b1_0010_0100);
+        let mut picked = BitBoard::EMPTY;
+        for _ in 0..100 {
+            assert!(random.below(3) < 3);
+            let index = random.pick(squares);
+            assert!(squares.get(index));
+            picked.set(index);
+        }
+        assert_eq!(picked, squares);
+    }
+}
@@ -0,0 +1,149 @@
+use std::io::{stdin, BufRead};
+use tictactoe::board::bit_board::BitBoard;
+use tictactoe::board::render::BoardRenderer;
+use tictactoe::evaluation::evaluate;
+use tictactoe::position::Position;
+
+use crate::messages::{Language, Message};
+use crate::save::{load_moves, LoadError};
+
+/// Perfect-play result of a position, or how it ended
+fn describe(position: Position) -> Message<'static> {
+    match position.winner() {
+        Some(x) => Message::HasWon(x),
+        None if position.is_over() => Message::Drawn,
+        None => match evaluate(position) {
+            1 => Message::CanForceWin(true),
+            -1 => Message::CanForceWin(false),
+            _ => Message::Drawable,
+        },
+    }
+}
+
+/// Every position of the game, from the empty board to the last move
+fn positions(x_moves_first: bool, moves: &[u8]) -> Vec<Position> {
+    let mut position = Position {
+        x_to_move: x_moves_first,
+        ..Position::START
+    };
+    let mut positions = vec![position];
+    for index in moves {
+        position = position.play(*index);
+        positions.push(position);
+    }
+    positions
+}
+
+fn show(
+    renderer: &mut BoardRenderer,
+    language: Language,
+    positions: &[Position],
+    moves: &[u8],
+    ply: usize,
+) {
+    let position = positions[ply];
+    renderer.last_move = ply.checked_sub(1).map(|last| moves[last]);
+    renderer.winning = [position.board.x, position.board.o]
+        .iter()
+        .flat_map(BitBoard::winning_lines)
+        .fold(BitBoard::EMPTY, |squares, line| squares | line.bit_board());
+    println!("{}", renderer.render(&position.board));
+
+    let say = |message: &Message| println!("{}", message.text(language));
+    if ply == 0 {
+        let result = describe(position).text(language);
+        say(&Message::ReplayStart(moves.len(), &result));
+        return;
+    }
+    let before = positions[ply - 1];
+    let square = renderer.scheme.name(moves[ply - 1]);
+    say(&Message::Ply(ply, moves.len(), before.x_to_move, &square));
+    say(&Message::Before(&describe(before).text(language)));
+    say(&Message::After(&describe(position).text(language)));
+    // Only a mistake can change the perfect-play result
+    if evaluate(before) != evaluate(position) {
+        say(&Message::ResultChanged);
+    }
+}
+
+/// Steps through a saved game forwards and backwards
+pub fn run(path: &str, mut renderer: BoardRenderer, language: Language) -> Result<(), LoadError> {
+    let (x_moves_first, moves) = load_moves(path)?;
+    let positions = positions(x_moves_first, &moves);
+
+    let keys = Message::ReplayKeys.text(language);
+    println!("{keys}");
+    let mut ply = 0;
+    show(&mut renderer, language, &positions, &moves, ply);
+    for line in stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let previous = ply;
+        match line.trim() {
+            "" | "n" | "next" => ply = (ply + 1).min(moves.len()),
+            "b" | "back" => ply = ply.saturating_sub(1),
+            "s" | "start" => ply = 0,
+            "e" | "end" => ply = moves.len(),
+            "q" | "quit" => break,
+            input => match input.parse() {
+                Ok(number) if number <= moves.len() => ply = number,
+                _ => {
+                    println!("{keys}");
+                    continue;
+                }
+            },
+        }
+        if ply == previous && (ply == 0 || ply == moves.len()) {
+            let message = if ply == 0 {
+                Message::AlreadyAtStart
+            } else {
+                Message::AlreadyAtEnd
+            };
+            println!("{}", message.text(language));
+        } else {
+            show(&mut renderer, language, &positions, &moves, ply);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn described(position: Position) -> String {
+        describe(position).text(Language::English)
+    }
+
+    #[test]
+    fn steps_through_the_moves() {
+        let positions = positions(false, &[4, 0]);
+        assert_eq!(positions.len(), 3);
+        assert!(!positions[0].x_to_move);
+        assert!(positions[1].board.o.get(4));
+        assert!(positions[2].board.x.get(0));
+        assert!(!positions[2].x_to_move);
+    }
+
+    #[test]
+    fn describes_each_position() {
+        let start = positions(true, &[]);
+        assert_eq!(
+            described(start[0]),
+            Message::Drawable.text(Language::English)
+        );
+        // After a1 b1 b2 c1, X finishes the diagonal on c3
+        let game = positions(true, &[0, 1, 4, 2, 8]);
+        assert_eq!(
+            described(game[4]),
+            Message::CanForceWin(true).text(Language::English)
+        );
+        assert_eq!(
+            described(game[5]),
+            Message::HasWon(true).text(Language::English)
+        );
+        let drawn = positions(true, &[0, 4, 8, 1, 7, 6, 2, 5, 3]);
+        assert_eq!(described(drawn[9]), Message::Drawn.text(Language::English));
+    }
+}
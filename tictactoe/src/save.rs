@@ -0,0 +1,422 @@
+use std::fmt::Write as _;
+use std::time::Duration;
+use std::{fs, io};
+use tictactoe::agent::{Agent, MAX_LEVEL};
+use tictactoe::board::{parse_square, square_name};
+use tictactoe::controller::GameController;
+use tictactoe::position::Position;
+use tictactoe::random::Random;
+
+use crate::clock::{Clock, TimeControl};
+use crate::scoreboard::Scoreboard;
+use crate::Game;
+
+/// First line of every saved game
+const HEADER: &str = "tictactoe save 1";
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    /// Not a saved game at all
+    Header,
+    /// A line that couldn't be read, numbered from 1
+    Line(usize, String),
+    Missing(&'static str),
+    /// A saved move the rules don't allow, such as a taken square
+    IllegalMove(String),
+}
+
+fn agent_name(agent: Option<Agent>) -> String {
+    match agent {
+        None => String::from("human"),
+        Some(Agent::Perfect { level }) => format!("perfect {level}"),
+        Some(Agent::Random) => String::from("random"),
+        Some(Agent::Mcts { iterations }) => format!("mcts {iterations}"),
+    }
+}
+
+/// A computer player, written by `agent_name`
+fn parse_agent(words: &[&str]) -> Option<Agent> {
+    Some(match words {
+        ["perfect", level] => Agent::Perfect {
+            level: level
+                .parse()
+                .ok()
+                .filter(|level| (1..=MAX_LEVEL).contains(level))?,
+        },
+        ["random"] => Agent::Random,
+        ["mcts", iterations] => Agent::Mcts {
+            iterations: iterations.parse().ok()?,
+        },
+        _ => return None,
+    })
+}
+
+fn parse_seconds(text: &str) -> Option<Duration> {
+    Duration::try_from_secs_f64(text.parse().ok()?).ok()
+}
+
+/// `clock` line values after the first word, written by `Game::saved`
+fn parse_clock(kind: &str, times: &[&str]) -> Option<Clock> {
+    let times: Vec<Duration> = times
+        .iter()
+        .map(|time| parse_seconds(time))
+        .collect::<Option<_>>()?;
+    let (control, x, o) = match (kind, &times[..]) {
+        ("fischer", [base, increment, x, o]) => (
+            TimeControl::Fischer {
+                base: *base,
+                increment: *increment,
+            },
+            *x,
+            *o,
+        ),
+        ("movetime", [time, x, o]) => (TimeControl::PerMove(*time), *x, *o),
+        _ => return None,
+    };
+    Some(Clock::with_remaining(control, x, o))
+}
+
+/// Checks every move with the rules, including those redo would play back,
+/// returning the played and the undone squares
+fn check_moves(
+    x_moves_first: bool,
+    moves: &[&str],
+    undone_moves: &[&str],
+) -> Result<(Vec<u8>, Vec<u8>), LoadError> {
+    let mut position = Position {
+        x_to_move: x_moves_first,
+        ..Position::START
+    };
+    let mut history = Vec::new();
+    let mut undone = Vec::new();
+    // Undone moves are played back most recent first
+    for (name, played) in moves
+        .iter()
+        .map(|name| (name, true))
+        .chain(undone_moves.iter().rev().map(|name| (name, false)))
+    {
+        let index = parse_square(name)
+            .filter(|index| position.moves().get(*index))
+            .ok_or_else(|| LoadError::IllegalMove((*name).to_owned()))?;
+        position = position.play(index);
+        if played {
+            history.push(index);
+        } else {
+            undone.push(index);
+        }
+    }
+    undone.reverse();
+    Ok((history, undone))
+}
+
+fn parse_side(text: &str) -> Option<bool> {
+    match text {
+        "x" => Some(true),
+        "o" => Some(false),
+        _ => None,
+    }
+}
+
+const fn side_name(x: bool) -> &'static str {
+    if x {
+        "x"
+    } else {
+        "o"
+    }
+}
+
+/// Everything in a saved game, checked before any of it replaces the current game
+struct Saved {
+    x_moves_first: bool,
+    x_agent: Option<Agent>,
+    o_agent: Option<Agent>,
+    random: Random,
+    games: u32,
+    stop_early: bool,
+    scoreboard: Scoreboard,
+    clock: Option<Clock>,
+    flag_fell: Option<bool>,
+    history: Vec<u8>,
+    undone: Vec<u8>,
+}
+
+impl Saved {
+    fn parse(text: &str) -> Result<Self, LoadError> {
+        let mut lines = text.lines().enumerate();
+        if lines.next().map(|(_, line)| line.trim()) != Some(HEADER) {
+            return Err(LoadError::Header);
+        }
+
+        let mut first = None;
+        let (mut x_agent, mut o_agent) = (None, None);
+        let mut random = None;
+        let mut games = 1;
+        let mut stop_early = false;
+        let mut scoreboard = Scoreboard::default();
+        let mut clock = None;
+        let mut flag_fell = None;
+        let mut moves = Vec::new();
+        let mut undone_moves = Vec::new();
+
+        for (number, line) in lines {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let invalid = || LoadError::Line(number + 1, line.to_owned());
+            match words[..] {
+                [] => {}
+                ["first", side] => first = Some(parse_side(side).ok_or_else(invalid)?),
+                ["x", "human"] => x_agent = Some(None),
+                ["o", "human"] => o_agent = Some(None),
+                ["x", ref agent @ ..] => {
+                    x_agent = Some(Some(parse_agent(agent).ok_or_else(invalid)?));
+                }
+                ["o", ref agent @ ..] => {
+                    o_agent = Some(Some(parse_agent(agent).ok_or_else(invalid)?));
+                }
+                ["random", state] => {
+                    random = Some(
+                        state
+                            .parse()
+                            .ok()
+                            .and_then(Random::from_state)
+                            .ok_or_else(invalid)?,
+                    );
+                }
+                ["games", count] => {
+                    games = count
+                        .parse()
+                        .ok()
+                        .filter(|games| *games > 0)
+                        .ok_or_else(invalid)?;
+                }
+                ["stop-early"] => stop_early = true,
+                ["score", x_wins, o_wins, draws] => {
+                    let parse = |count: &str| count.parse().map_err(|_| invalid());
+                    scoreboard = Scoreboard {
+                        x_wins: parse(x_wins)?,
+                        o_wins: parse(o_wins)?,
+                        draws: parse(draws)?,
+                    };
+                }
+                ["clock", kind, ref times @ ..] => {
+                    clock = Some(parse_clock(kind, times).ok_or_else(invalid)?);
+                }
+                ["flag", side] => flag_fell = Some(parse_side(side).ok_or_else(invalid)?),
+                ["moves", ref names @ ..] => moves = names.to_vec(),
+                ["undone", ref names @ ..] => undone_moves = names.to_vec(),
+                _ => return Err(invalid()),
+            }
+        }
+
+        let x_moves_first = first.ok_or(LoadError::Missing("first"))?;
+        let (history, undone) = check_moves(x_moves_first, &moves, &undone_moves)?;
+        Ok(Self {
+            x_moves_first,
+            x_agent: x_agent.ok_or(LoadError::Missing("x"))?,
+            o_agent: o_agent.ok_or(LoadError::Missing("o"))?,
+            random: random.ok_or(LoadError::Missing("random"))?,
+            games,
+            stop_early,
+            scoreboard,
+            clock,
+            flag_fell,
+            history,
+            undone,
+        })
+    }
+}
+
+/// Who moved first and the moves played, from a saved game
+pub fn load_moves(path: &str) -> Result<(bool, Vec<u8>), LoadError> {
+    let saved = Saved::parse(&fs::read_to_string(path).map_err(LoadError::Io)?)?;
+    Ok((saved.x_moves_first, saved.history))
+}
+
+impl Game<'_> {
+    /// The whole session as text: settings, clocks and moves
+    fn saved(&self) -> String {
+        let names = |moves: &[u8]| {
+            moves.iter().fold(String::new(), |mut names, index| {
+                write!(names, " {}", square_name(*index)).unwrap();
+                names
+            })
+        };
+
+        let mut text = String::new();
+        writeln!(text, "{HEADER}").unwrap();
+        writeln!(text, "first {}", side_name(self.controller.x_moves_first())).unwrap();
+        writeln!(text, "x {}", agent_name(self.controller.agent(true))).unwrap();
+        writeln!(text, "o {}", agent_name(self.controller.agent(false))).unwrap();
+        // The generator's state rather than the seed, so the computer's choices carry on from here
+        writeln!(text, "random {}", self.controller.random().state()).unwrap();
+        writeln!(text, "games {}", self.games).unwrap();
+        if self.stop_early {
+            writeln!(text, "stop-early").unwrap();
+        }
+        let Scoreboard {
+            x_wins,
+            o_wins,
+            draws,
+        } = self.scoreboard;
+        writeln!(text, "score {x_wins} {o_wins} {draws}").unwrap();
+        if let Some(clock) = &self.clock {
+            let (x, o) = (clock.remaining(true), clock.remaining(false));
+            match clock.control {
+                TimeControl::Fischer { base, increment } => writeln!(
+                    text,
+                    "clock fischer {:.3} {:.3} {:.3} {:.3}",
+                    base.as_secs_f64(),
+                    increment.as_secs_f64(),
+                    x.as_secs_f64(),
+                    o.as_secs_f64()
+                ),
+                TimeControl::PerMove(time) => writeln!(
+                    text,
+                    "clock movetime {:.3} {:.3} {:.3}",
+                    time.as_secs_f64(),
+                    x.as_secs_f64(),
+                    o.as_secs_f64()
+                ),
+            }
+            .unwrap();
+        }
+        if let Some(side) = self.controller.forfeited() {
+            writeln!(text, "flag {}", side_name(side)).unwrap();
+        }
+        writeln!(text, "moves{}", names(self.controller.history())).unwrap();
+        writeln!(text, "undone{}", names(self.controller.undone())).unwrap();
+        text
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.saved())
+    }
+
+    /// Replaces the game with the one saved in `path`, leaving it as it was if the file isn't valid
+    pub fn load(&mut self, path: &str) -> Result<(), LoadError> {
+        let saved = Saved::parse(&fs::read_to_string(path).map_err(LoadError::Io)?)?;
+        self.controller = GameController::new(
+            saved.x_agent,
+            saved.o_agent,
+            saved.x_moves_first,
+            saved.random,
+        );
+        self.controller
+            .restore(saved.x_moves_first, saved.history, saved.undone);
+        if let Some(side) = saved.flag_fell {
+            self.controller.forfeit(side);
+        }
+        self.games = saved.games;
+        self.stop_early = saved.stop_early;
+        self.scoreboard = saved.scoreboard;
+        self.clock = saved.clock;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::game;
+
+    #[test]
+    fn saves_and_loads_a_session() {
+        let mut output = Vec::new();
+        let mut game = game("", &mut output);
+        game.controller = GameController::new(
+            None,
+            Some(Agent::Mcts { iterations: 500 }),
+            false,
+            Random::new(7),
+        );
+        for index in [4, 0, 8] {
+            game.controller.play(index).unwrap();
+        }
+        // Back to the human, past the computer's reply
+        game.controller.undo();
+        game.games = 3;
+        game.scoreboard.record(Some(false));
+        game.clock = Some(Clock::with_remaining(
+            TimeControl::PerMove(Duration::from_secs(5)),
+            Duration::from_millis(1500),
+            Duration::from_secs(2),
+        ));
+        let text = game.saved();
+        assert_eq!(
+            text,
+            format!(
+                "tictactoe save 1\nfirst o\nx human\no mcts 500\nrandom {}\ngames 3\nscore 0 1 0\n\
+                 clock movetime 5.000 1.500 2.000\nmoves b2\nundone c3 a1\n",
+                game.controller.random().state()
+            )
+        );
+
+        let saved = Saved::parse(&text).unwrap();
+        assert!(!saved.x_moves_first);
+        assert!(saved.x_agent.is_none());
+        assert!(saved.o_agent == Some(Agent::Mcts { iterations: 500 }));
+        assert_eq!(saved.random.state(), game.controller.random().state());
+        assert_eq!((saved.games, saved.scoreboard.o_wins), (3, 1));
+        assert_eq!(saved.history, [4]);
+        assert_eq!(saved.undone, [8, 0]);
+        let clock = saved.clock.unwrap();
+        assert_eq!(clock.remaining(true), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn loads_into_a_game() {
+        let path = std::env::temp_dir().join(format!("tictactoe-save-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        fs::write(
+            path,
+            "tictactoe save 1\nfirst x\nx perfect 3\no human\nrandom 9\nflag o\nmoves a1 b2\nundone\n",
+        )
+        .unwrap();
+        let mut output = Vec::new();
+        let mut game = game("", &mut output);
+        let loaded = game.load(path);
+        fs::remove_file(path).unwrap();
+        assert!(loaded.is_ok());
+        assert_eq!(game.controller.history(), [0, 4]);
+        assert!(game.controller.agent(true) == Some(Agent::Perfect { level: 3 }));
+        assert_eq!(game.controller.forfeited(), Some(false));
+        assert!(matches!(game.load(path), Err(LoadError::Io(_))));
+    }
+
+    #[test]
+    fn rejects_invalid_saves() {
+        let parse = |text: &str| Saved::parse(text).err();
+        let valid = "tictactoe save 1\nfirst x\nx human\no random\nrandom 1\n";
+        assert!(parse(valid).is_none());
+        assert!(matches!(parse("a saved game\n"), Some(LoadError::Header)));
+        assert!(matches!(
+            parse(&format!("{valid}games 0\n")),
+            Some(LoadError::Line(6, line)) if line == "games 0"
+        ));
+        assert!(matches!(
+            parse(&format!("{valid}o perfect 99\n")),
+            Some(LoadError::Line(6, _))
+        ));
+        assert!(matches!(
+            parse(&format!("{valid}random 0\n")),
+            Some(LoadError::Line(6, _))
+        ));
+        assert!(matches!(
+            parse("tictactoe save 1\nx human\no human\nrandom 1\n"),
+            Some(LoadError::Missing("first"))
+        ));
+        assert!(matches!(
+            parse(&format!("{valid}moves a1 a1\n")),
+            Some(LoadError::IllegalMove(name)) if name == "a1"
+        ));
+        // Undone moves have to fit after the played ones too
+        assert!(matches!(
+            parse(&format!("{valid}moves a1\nundone b1 a1\n")),
+            Some(LoadError::IllegalMove(name)) if name == "a1"
+        ));
+        assert!(matches!(
+            parse(&format!("{valid}moves a1 b1 a2 b2 a3 c3\n")),
+            Some(LoadError::IllegalMove(name)) if name == "c3"
+        ));
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt::Write;
+
+/// Results of a match so far
+#[derive(Default)]
+pub struct Scoreboard {
+    pub x_wins: u32,
+    pub o_wins: u32,
+    pub draws: u32,
+}
+
+impl Scoreboard {
+    /// `winner` is `Some(true)` if X won, `None` for a draw
+    pub const fn record(&mut self, winner: Option<bool>) {
+        match winner {
+            Some(true) => self.x_wins += 1,
+            Some(false) => self.o_wins += 1,
+            None => self.draws += 1,
+        }
+    }
+
+    pub const fn played(&self) -> u32 {
+        self.x_wins + self.o_wins + self.draws
+    }
+
+    /// Whether the leader can't be caught in the rest of a match of `games` games
+    pub const fn is_decided(&self, games: u32) -> bool {
+        let left = games.saturating_sub(self.played());
+        self.x_wins > self.o_wins + left || self.o_wins > self.x_wins + left
+    }
+
+    /// Won, lost and drawn games for each side, under `headings` for the player, won, lost and drawn columns
+    pub fn table(&self, x_name: &str, o_name: &str, headings: [&str; 4]) -> String {
+        let [player, won, lost, drawn] = headings;
+        // Columns are as wide as their heading, and at least as wide as in English
+        let player_width = player.chars().count().max(16);
+        let won_width = won.chars().count().max(4);
+        let lost_width = lost.chars().count().max(4);
+        let drawn_width = drawn.chars().count().max(5);
+        let mut table = String::new();
+        writeln!(
+            table,
+            "{player:<player_width$} {won:>won_width$}  {lost:>lost_width$}  {drawn:>drawn_width$}"
+        )
+        .unwrap();
+        for (name, won, lost) in [
+            (format!("X ({x_name})"), self.x_wins, self.o_wins),
+            (format!("O ({o_name})"), self.o_wins, self.x_wins),
+        ] {
+            writeln!(
+                table,
+                "{name:<player_width$} {won:>won_width$}  {lost:>lost_width$}  {:>drawn_width$}",
+                self.draws
+            )
+            .unwrap();
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_when_the_match_is_decided() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.record(Some(true));
+        scoreboard.record(None);
+        assert_eq!(scoreboard.played(), 2);
+        assert!(!scoreboard.is_decided(5));
+        scoreboard.record(Some(true));
+        // 2–0 with two games left can still be caught up
+        assert!(!scoreboard.is_decided(5));
+        scoreboard.record(Some(true));
+        assert!(scoreboard.is_decided(5));
+        assert!(scoreboard.is_decided(3));
+    }
+
+    #[test]
+    fn lines_up_the_table() {
+        let scoreboard = Scoreboard {
+            x_wins: 2,
+            o_wins: 1,
+            draws: 3,
+        };
+        let table = scoreboard.table("human", "perfect", ["Player", "Won", "Lost", "Drawn"]);
+        assert_eq!(
+            table,
+            "Player            Won  Lost  Drawn\n\
+             X (human)           2     1      3\n\
+             O (perfect)         1     2      3\n"
+        );
+        // A longer heading widens its column
+        let table = scoreboard.table("human", "perfect", ["Jugador", "Ganadas", "P", "E"]);
+        assert!(table.starts_with("Jugador          Ganadas     P      E\n"));
+        assert!(table.contains("X (human)              2     1      3\n"));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::messages::Message;
+use crate::Game;
+
+/// Exit codes of a scripted game. Usage errors exit with 2 and unreadable files with 1
+pub const X_WON: i32 = 10;
+pub const O_WON: i32 = 11;
+pub const DRAWN: i32 = 12;
+/// The moves ran out with a human to move
+pub const UNFINISHED: i32 = 13;
+pub const ILLEGAL_MOVE: i32 = 3;
+
+/// Where the moves of a scripted game come from
+pub enum Moves {
+    /// Given on the command line
+    List(String),
+    /// Read from a file, or stdin for `-`
+    File(String),
+}
+
+/// Moves from a file, or stdin for `-`
+pub fn read_moves(path: &str) -> std::io::Result<String> {
+    if path == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
+
+impl Game<'_> {
+    /// Plays `moves`, separated by whitespace, for whichever side is to move. Once they run out
+    /// the computer players move until the game ends or a human is to move. Prints the final
+    /// position and returns the exit code for the result
+    pub fn run_script(&mut self, moves: &str) -> i32 {
+        for (ply, input) in moves.split_whitespace().enumerate() {
+            let played = self
+                .renderer
+                .scheme
+                .parse(input)
+                .is_some_and(|index| self.controller.play(index).is_ok());
+            if !played {
+                self.show();
+                eprintln!("{}", self.text(&Message::IllegalScriptMove(ply + 1, input)));
+                return ILLEGAL_MOVE;
+            }
+        }
+        while self.controller.is_agent_turn() {
+            self.ai_turn();
+        }
+        self.show();
+
+        match self.controller.winner() {
+            Some(true) => X_WON,
+            Some(false) => O_WON,
+            None if self.controller.is_over() => DRAWN,
+            None => UNFINISHED,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tictactoe::agent::{Agent, MAX_LEVEL};
+    use tictactoe::controller::GameController;
+    use tictactoe::random::Random;
+
+    use crate::tests::game;
+
+    /// Exit code of a scripted game with these players, `None` for a human
+    fn script(moves: &str, x: Option<Agent>, o: Option<Agent>) -> i32 {
+        let mut output = Vec::new();
+        let mut game = game("", &mut output);
+        game.controller = GameController::new(x, o, true, Random::new(0));
+        game.run_script(moves)
+    }
+
+    #[test]
+    fn exits_with_the_result() {
+        assert_eq!(script("a1 b1 b2 c1 c3", None, None), X_WON);
+        assert_eq!(script("a1 b2 a2 a3 c1 b1 b3 c2 c3", None, None), DRAWN);
+        assert_eq!(script("b2 a1", None, None), UNFINISHED);
+        assert_eq!(script("", None, None), UNFINISHED);
+    }
+
+    #[test]
+    fn stops_at_an_illegal_move() {
+        assert_eq!(script("a1 a1", None, None), ILLEGAL_MOVE);
+        assert_eq!(script("a1 d4", None, None), ILLEGAL_MOVE);
+        assert_eq!(script("a1 b1 b2 c1 c3 a3", None, None), ILLEGAL_MOVE);
+    }
+
+    #[test]
+    fn computer_players_finish_the_game() {
+        let perfect = Agent::from_name("perfect", MAX_LEVEL);
+        // Once X leaves the b file open, O wins however the computers go about it
+        assert_eq!(script("a1 b2 c3 b1 a2", perfect, perfect), O_WON);
+        assert_eq!(script("b2", None, perfect), UNFINISHED);
+        assert_eq!(script("", perfect, perfect), DRAWN);
+    }
+}
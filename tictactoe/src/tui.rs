@@ -0,0 +1,374 @@
+use std::fmt::Write as _;
+use std::io::{IsTerminal, Read, Write};
+use std::panic;
+use std::process::{Command, Stdio};
+use tictactoe::board::render::BoardRenderer;
+use tictactoe::controller::GameEvent;
+use tictactoe::evaluation::outcomes;
+
+use crate::messages::Message;
+use crate::Game;
+
+/// Screen line and column where the board starts. The status lines follow it
+const BOARD_TOP: u16 = 3;
+const BOARD_LEFT: u16 = 2;
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Shows the cursor, stops mouse reporting, leaves the alternate screen and puts back the terminal settings
+fn restore(settings: &str) {
+    let mut stdout = std::io::stdout();
+    let _ = write!(stdout, "\x1b[?1006l\x1b[?1000l\x1b[?25h\x1b[?1049l");
+    let _ = stdout.flush();
+    stty(&[settings]);
+}
+
+/// Raw terminal mode for as long as it's alive, even if the game panics
+struct RawMode {
+    settings: String,
+}
+
+impl RawMode {
+    fn enable() -> Option<Self> {
+        let settings = stty(&["-g"])?;
+        stty(&["raw", "-echo"])?;
+
+        let hook_settings = settings.clone();
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            restore(&hook_settings);
+            previous_hook(info);
+        }));
+
+        let mut stdout = std::io::stdout();
+        let _ = write!(stdout, "\x1b[?1049h\x1b[?25l\x1b[?1000h\x1b[?1006h");
+        let _ = stdout.flush();
+        Some(Self { settings })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        restore(&self.settings);
+        // While panicking the hook has already run, and can't be taken
+        if !std::thread::panicking() {
+            let _ = panic::take_hook();
+        }
+    }
+}
+
+enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Enter,
+    /// Left click on a screen column and line
+    Click(u16, u16),
+    Char(char),
+    Other,
+}
+
+/// Square under a screen position, if any
+fn square_at(renderer: &BoardRenderer, column: u16, line: u16) -> Option<u8> {
+    renderer.square_at(
+        usize::from(column.checked_sub(BOARD_LEFT)?),
+        usize::from(line.checked_sub(BOARD_TOP)?),
+    )
+}
+
+impl Game<'_> {
+    fn read_byte(&mut self) -> Option<u8> {
+        let mut byte = [0];
+        match self.stdin.read(&mut byte) {
+            Ok(1) => Some(byte[0]),
+            _ => None,
+        }
+    }
+
+    /// `None` once stdin is closed
+    fn read_key(&mut self) -> Option<Key> {
+        Some(match self.read_byte()? {
+            b'\r' | b'\n' | b' ' => Key::Enter,
+            0x1b => {
+                if self.read_byte()? != b'[' {
+                    return Some(Key::Other);
+                }
+                match self.read_byte()? {
+                    b'A' => Key::Up,
+                    b'B' => Key::Down,
+                    b'C' => Key::Right,
+                    b'D' => Key::Left,
+                    // SGR mouse report: ESC [ < button ; column ; line M
+                    b'<' => {
+                        let mut report = String::new();
+                        let end = loop {
+                            match self.read_byte()? {
+                                byte @ (b'M' | b'm') => break byte,
+                                byte => report.push(byte as char),
+                            }
+                        };
+                        let fields: Vec<u16> = report
+                            .split(';')
+                            .filter_map(|field| field.parse().ok())
+                            .collect();
+                        match fields[..] {
+                            [0, column, line] if end == b'M' => Key::Click(column, line),
+                            _ => Key::Other,
+                        }
+                    }
+                    _ => Key::Other,
+                }
+            }
+            byte => Key::Char(byte as char),
+        })
+    }
+
+    /// Draws the board like the line-based game, with the labels of the input scheme and the
+    /// cursor in reverse video, even over the winning line. `map` is as for [`Game::draw`]
+    fn board_renderer(&self, cursor: u8, map: Option<bool>) -> BoardRenderer {
+        let mut renderer = self.renderer;
+        renderer.last_move = None;
+        renderer.winning = self.winning_squares();
+        renderer.cursor = Some(cursor);
+        if let Some(distances) = map {
+            renderer.heatmap = outcomes(self.controller.position());
+            renderer.distances = distances;
+        }
+        renderer
+    }
+
+    /// `map` is `None` for plain cells, or `Some(distances)` to show the result of taking each one
+    fn draw(&mut self, cursor: u8, map: Option<bool>, message: &str) {
+        // The screen shows the game as it is, so of what led here only a takeback or a new game
+        // needs saying, when nothing else is
+        let note = self
+            .controller
+            .events()
+            .filter_map(|event| match event {
+                GameEvent::MovesUndone { count } => Some(Message::MovesUndone(count)),
+                GameEvent::BoardReset { x_moves_first } => Some(Message::BoardReset(x_moves_first)),
+                _ => None,
+            })
+            .next_back();
+        let message = match note {
+            Some(note) if message.is_empty() => self.text(&note),
+            _ => message.to_owned(),
+        };
+        let mut screen = format!("\x1b[1;1H{}\x1b[K", self.text(&Message::Title));
+
+        let board = self
+            .board_renderer(cursor, map)
+            .render(&self.controller.position().board);
+        let mut line = BOARD_TOP;
+        for text in board.lines() {
+            write!(screen, "\x1b[{line};{BOARD_LEFT}H{text}\x1b[K").unwrap();
+            line += 1;
+        }
+        write!(screen, "\x1b[{line};1H\x1b[K").unwrap();
+        let status_line = line + 1;
+
+        let status = self.win_message().unwrap_or_else(|| {
+            if self.controller.is_over() {
+                self.text(&Message::Draw)
+            } else {
+                format!(
+                    "{} | {}",
+                    self.text(&Message::ToMove(self.controller.position().x_to_move)),
+                    self.text(&self.evaluation())
+                )
+            }
+        });
+        let status = match self.clock_line() {
+            Some(clock_line) => format!("{status} | {clock_line}"),
+            None => status,
+        };
+        let moves: Vec<String> = self
+            .controller
+            .history()
+            .iter()
+            .map(|index| self.renderer.scheme.name(*index))
+            .collect();
+        write!(
+            screen,
+            "\x1b[{status_line};1H{status} | {}: {}\x1b[K",
+            self.text(&Message::Moves),
+            moves.join(" ")
+        )
+        .unwrap();
+        write!(screen, "\x1b[{};1H{message}\x1b[K", status_line + 1).unwrap();
+        write!(
+            screen,
+            "\x1b[{};1H{}\x1b[K\x1b[J",
+            status_line + 2,
+            self.text(&Message::Keys)
+        )
+        .unwrap();
+
+        self.stdout.write_all(screen.as_bytes()).unwrap();
+        self.stdout.flush().unwrap();
+    }
+
+    /// Full-screen game, played with the arrow keys and Enter or the mouse
+    pub fn run_tui(&mut self) {
+        if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+            eprintln!("{}", self.text(&Message::NeedsTerminal));
+            return;
+        }
+        let Some(_raw_mode) = RawMode::enable() else {
+            eprintln!("{}", self.text(&Message::NoRawMode));
+            return;
+        };
+        write!(self.stdout, "\x1b[2J").unwrap();
+
+        let mut cursor = 4;
+        let mut message = String::new();
+        // Cycles through off, letters and letters with distances
+        let mut map = None;
+        // A move the coach warned about, played if it's chosen again straight away
+        let mut warned = None;
+        loop {
+            while self.controller.is_agent_turn() {
+                self.ai_turn();
+            }
+            self.draw(cursor, map, &message);
+            message.clear();
+
+            self.start_clock();
+            let key = self.read_key();
+            self.check_flag();
+            let Some(key) = key else {
+                return;
+            };
+            let mut place = None;
+            match key {
+                Key::Up if cursor < 6 => cursor += 3,
+                Key::Down if cursor >= 3 => cursor -= 3,
+                Key::Right if cursor % 3 != 2 => cursor += 1,
+                Key::Left if cursor % 3 != 0 => cursor -= 1,
+                Key::Enter => place = Some(cursor),
+                Key::Click(column, line) => {
+                    if let Some(index) = square_at(&self.board_renderer(cursor, map), column, line)
+                    {
+                        cursor = index;
+                        place = Some(index);
+                    }
+                }
+                Key::Char('q' | '\x03') => return,
+                Key::Char('u') => {
+                    let changed = self.undo();
+                    if !changed {
+                        message = self.text(&Message::NothingToUndo);
+                    }
+                }
+                Key::Char('r') => {
+                    let changed = self.redo();
+                    if !changed {
+                        message = self.text(&Message::NothingToRedo);
+                    }
+                }
+                Key::Char('h') if !self.controller.is_over() => message = self.hint(),
+                Key::Char('m') => {
+                    map = match map {
+                        None => Some(false),
+                        Some(false) => Some(true),
+                        Some(true) => None,
+                    };
+                }
+                Key::Char('n') => self.new_game(self.controller.x_moves_first()),
+                Key::Char('s') => self.controller.swap_agents(),
+                _ => {}
+            }
+
+            let confirmed = place.is_some() && place == warned;
+            warned = None;
+            if let Some(index) = place {
+                if self.controller.is_over() {
+                    message = self.text(&Message::GameOverPress);
+                } else if self.controller.position().board.occupied().get(index) {
+                    message = self.text(&Message::Occupied);
+                } else if let Some(warning) = self.coach_warning(index).filter(|_| !confirmed) {
+                    message = self.text(&Message::ChooseAgain(&warning));
+                    warned = Some(index);
+                } else {
+                    self.make_move(index);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::game;
+
+    #[test]
+    fn reads_keys_and_clicks() {
+        let mut output = Vec::new();
+        let mut game = game(
+            "\x1b[A\x1b[D\r\x1b[<0;9;4M\x1b[<0;9;4m\x1b[<2;9;4Mq\x1bx",
+            &mut output,
+        );
+        assert!(matches!(game.read_key(), Some(Key::Up)));
+        assert!(matches!(game.read_key(), Some(Key::Left)));
+        assert!(matches!(game.read_key(), Some(Key::Enter)));
+        assert!(matches!(game.read_key(), Some(Key::Click(9, 4))));
+        // A release or a right click isn't a move
+        assert!(matches!(game.read_key(), Some(Key::Other)));
+        assert!(matches!(game.read_key(), Some(Key::Other)));
+        assert!(matches!(game.read_key(), Some(Key::Char('q'))));
+        assert!(matches!(game.read_key(), Some(Key::Other)));
+        assert!(game.read_key().is_none());
+    }
+
+    #[test]
+    fn clicks_land_on_the_drawn_squares() {
+        let mut output = Vec::new();
+        let game = game("", &mut output);
+        let renderer = game.board_renderer(4, None);
+        let board = renderer.render(&game.controller.position().board);
+        // Every square is somewhere on the screen, and the corner above the board is none of them
+        for index in 0..9 {
+            let found = board.lines().enumerate().any(|(line, text)| {
+                (0..text.chars().count()).any(|column| {
+                    square_at(
+                        &renderer,
+                        BOARD_LEFT + u16::try_from(column).unwrap(),
+                        BOARD_TOP + u16::try_from(line).unwrap(),
+                    ) == Some(index)
+                })
+            });
+            assert!(found, "square {index}");
+        }
+        assert_eq!(square_at(&renderer, 1, 1), None);
+    }
+
+    #[test]
+    fn keeps_colour_off_when_asked() {
+        let mut output = Vec::new();
+        let mut game = game("", &mut output);
+        let board = game
+            .board_renderer(4, None)
+            .render(&game.controller.position().board);
+        // Only the cursor is marked, in reverse video
+        assert_eq!(board.matches('\x1b').count(), 2);
+        assert!(board.contains("\x1b[7m-\x1b[0m"));
+        game.renderer.colour = true;
+        game.controller.play(4).unwrap();
+        let board = game
+            .board_renderer(0, None)
+            .render(&game.controller.position().board);
+        assert!(board.contains("\x1b[1;31mX"));
+    }
+}